@@ -0,0 +1,88 @@
+// allostasis.rs
+//
+// Allostatic load (SLOW MOOD)
+// ---------------------------
+// Tension is fast: it spikes and settles within a few ticks.
+// Allostatic load is slow: it integrates how long the brain has
+// been strained (tension high, energy low) with a long time
+// constant, and only drains during sustained relief.
+//
+// Chronic load feeds back into the fast physiology:
+//
+//     baseline tension creeps up
+//     stability grows slower
+//     shocks hit harder
+
+use crate::plasticity::Plasticity;
+use crate::state::{StateVector, ENERGY, TENSION};
+
+#[derive(Debug, Clone)]
+pub struct Allostasis {
+    /// Accumulated strain, 0 (rested) .. 1 (worn out)
+    pub load: f32,
+
+    /// Time constant in steps (larger = slower mood)
+    pub tau: f32,
+
+    /// Tension above this counts as strain
+    pub tension_set_point: f32,
+
+    /// Energy below this counts as deficit
+    pub energy_floor: f32,
+
+    /// Resting tension never settles below this at full load
+    pub tension_baseline: f32,
+
+    /// Fraction of the gap to that baseline closed per thinking tick
+    pub baseline_pull: f32,
+
+    /// Fraction of stability gain lost at full load
+    pub stability_drag: f32,
+
+    /// Fraction of extra shock sensitivity at full load
+    pub sensitivity_boost: f32,
+}
+
+impl Allostasis {
+    /// Instantaneous strain of one state, 0..1
+    pub fn strain(&self, s: &StateVector) -> f32 {
+        let tension = (s[TENSION] - self.tension_set_point).max(0.0);
+        let deficit = (self.energy_floor - s[ENERGY]).max(0.0);
+        (tension + deficit).min(1.0)
+    }
+
+    /// One step: load relaxes toward the current strain
+    pub fn update(&mut self, s: &StateVector) {
+        self.load += (self.strain(s) - self.load) / self.tau.max(1.0);
+        self.load = self.load.clamp(0.0, 1.0);
+    }
+
+    /// Reaction rates as bent by chronic load
+    pub fn modulate(&self, p: Plasticity) -> Plasticity {
+        Plasticity {
+            shock_sensitivity: p.shock_sensitivity * (1.0 + self.load * self.sensitivity_boost),
+            stability_gain_rate: p.stability_gain_rate * (1.0 - self.load * self.stability_drag),
+            energy_gain_rate: p.energy_gain_rate,
+        }
+    }
+
+    /// Tension drift for one thinking tick (up toward the loaded baseline)
+    pub fn tension_drift(&self, tension: f32) -> f32 {
+        (self.load * self.tension_baseline - tension).max(0.0) * self.baseline_pull
+    }
+}
+
+impl Default for Allostasis {
+    fn default() -> Self {
+        Self {
+            load: 0.0,
+            tau: 200.0,
+            tension_set_point: 0.6,
+            energy_floor: 0.4,
+            tension_baseline: 0.5,
+            baseline_pull: 0.05,
+            stability_drag: 0.5,
+            sensitivity_boost: 0.5,
+        }
+    }
+}
@@ -0,0 +1,155 @@
+// behavior.rs
+//
+// Behavioural output (THE NEWBORN ACTS)
+// -------------------------------------
+// The brain is no longer purely passive: a policy reads the
+// internal state and the dominant basin and emits one discrete
+// behaviour with an intensity. Acting is not free — the body
+// pays for it (crying drains energy).
+//
+// Policies are pluggable so experiments can swap the mapping.
+
+use std::fmt::Debug;
+
+use crate::bias::BiasField;
+use crate::state::{StateVector, ENERGY, STABILITY, TENSION};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Behavior {
+    Cry,
+    Calm,
+    Orient,
+    Sleep,
+    Seek,
+}
+
+/// One emitted behaviour
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Action {
+    pub behavior: Behavior,
+    /// 0..1
+    pub intensity: f32,
+}
+
+impl Action {
+    pub fn new(behavior: Behavior, intensity: f32) -> Self {
+        Self {
+            behavior,
+            intensity: intensity.clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// What a policy gets to see
+#[derive(Debug, Clone, Copy)]
+pub struct BehaviorInput<'a> {
+    pub state: &'a StateVector,
+
+    /// Strongest basin, if any
+    pub dominant: Option<&'a BiasField>,
+
+    /// 0..1, from the circadian clock (0 when timeless)
+    pub sleep_pressure: f32,
+
+    /// Highest level among urgent drives (0 when none is urgent)
+    pub urgency: f32,
+}
+
+pub trait Policy: Debug {
+    fn act(&mut self, input: &BehaviorInput) -> Action;
+}
+
+/// Original newborn policy: fixed thresholds, first match wins
+///
+/// cry > sleep > seek > orient > calm
+#[derive(Debug, Clone)]
+pub struct ThresholdPolicy {
+    /// Tension above this -> cry
+    pub cry_tension: f32,
+
+    /// Sleep pressure above this, or energy below `exhausted` -> sleep
+    pub sleep_pressure: f32,
+    pub exhausted: f32,
+
+    /// Approach tendency of the dominant basin above this -> seek
+    pub seek_tendency: f32,
+
+    /// Tension above this (but below crying) -> orient
+    pub orient_tension: f32,
+}
+
+impl Default for ThresholdPolicy {
+    fn default() -> Self {
+        Self {
+            cry_tension: 0.9,
+            sleep_pressure: 0.7,
+            exhausted: 0.15,
+            seek_tendency: 0.02,
+            orient_tension: 0.6,
+        }
+    }
+}
+
+impl Policy for ThresholdPolicy {
+    fn act(&mut self, input: &BehaviorInput) -> Action {
+        let s = input.state;
+
+        if s[TENSION] > self.cry_tension {
+            let over = (s[TENSION] - self.cry_tension) / self.cry_tension;
+            return Action::new(Behavior::Cry, 0.5 + over);
+        }
+
+        if input.sleep_pressure > self.sleep_pressure || s[ENERGY] < self.exhausted {
+            return Action::new(Behavior::Sleep, input.sleep_pressure.max(1.0 - s[ENERGY]));
+        }
+
+        let approach = input.dominant.map_or(0.0, |b| b.tendency());
+        if input.urgency > 0.0 || approach > self.seek_tendency {
+            return Action::new(Behavior::Seek, input.urgency.max(approach * 10.0));
+        }
+
+        if s[TENSION] > self.orient_tension {
+            let alert = (s[TENSION] - self.orient_tension) / (self.cry_tension - self.orient_tension);
+            return Action::new(Behavior::Orient, alert);
+        }
+
+        Action::new(Behavior::Calm, s[STABILITY])
+    }
+}
+
+/// Physiological price of acting, per unit intensity
+#[derive(Debug, Clone)]
+pub struct ActionCosts {
+    pub cry_energy: f32,
+    /// Crying discharges a little tension
+    pub cry_tension_release: f32,
+    pub seek_energy: f32,
+    pub orient_energy: f32,
+}
+
+impl ActionCosts {
+    /// Apply the cost of `action` to the state
+    pub fn pay(&self, action: &Action, s: &mut StateVector) {
+        let k = action.intensity;
+        match action.behavior {
+            Behavior::Cry => {
+                s[ENERGY] -= self.cry_energy * k;
+                s[TENSION] -= self.cry_tension_release * k;
+            }
+            Behavior::Seek => s[ENERGY] -= self.seek_energy * k,
+            Behavior::Orient => s[ENERGY] -= self.orient_energy * k,
+            Behavior::Calm | Behavior::Sleep => {}
+        }
+    }
+}
+
+impl Default for ActionCosts {
+    fn default() -> Self {
+        Self {
+            cry_energy: 0.03,
+            cry_tension_release: 0.01,
+            seek_energy: 0.01,
+            orient_energy: 0.005,
+        }
+    }
+}
@@ -1,52 +1,304 @@
 // bias.rs
 
+use crate::metric::{Metric, L1};
+use crate::state::{ENERGY, STABILITY, TENSION};
+
 #[derive(Debug, Clone)]
 pub struct BiasField {
     // Preferred direction (structural memory)
-    pub dt_pref: f32,
-    pub ds_pref: f32,
-    pub de_pref: f32,
+    // one entry per state dimension
+    pub pref: Vec<f32>,
 
     // How deep this attractor is
     pub strength: f32,
+
+    // Where in state space this bias was reinforced (centroid)
+    pub context: Vec<f32>,
+
+    // Where the state ended up after those experiences (basin floor)
+    pub target: Vec<f32>,
+
+    // How many times it was reinforced
+    pub count: u32,
+
+    // Mean valence of the experiences that carved it
+    // (> 0 approach, < 0 avoid)
+    pub valence: f32,
+
+    // Running mean of absorbed deltas (Welford)
+    pub mean: Vec<f32>,
+
+    // Running co-moment of absorbed deltas, dims x dims row-major (Welford)
+    pub comoment: Vec<f32>,
+
+    // Forgetting steps since last reinforcement
+    pub idle: u32,
+
+    // How many times thinking replayed it
+    pub replays: u32,
+}
+
+/// How thinking picks which bias to replay
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BiasRecall {
+    /// Globally strongest bias, wherever the brain is
+    Strongest,
+    /// Best strength x proximity to the current state
+    Contextual { width: f32 },
+    /// Mixture of all biases weighted by strength x proximity
+    Blend { width: f32 },
+}
+
+/// When a blurred basin splits into two children
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Splitting {
+    /// Total variance (trace of covariance) above which a basin splits
+    pub variance_threshold: f32,
+
+    /// Minimum reinforcements before variance is trusted
+    pub min_count: u32,
+}
+
+impl Default for Splitting {
+    fn default() -> Self {
+        Self {
+            variance_threshold: 0.01,
+            min_count: 6,
+        }
+    }
+}
+
+/// What replaying a bias does to the state
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BiasSemantics {
+    /// Push along the preferred direction (never settles)
+    Direction,
+    /// Pull toward the basin target (settles at the target)
+    Attractor { pull: f32 },
 }
 
 impl BiasField {
-    pub fn new() -> Self {
+    pub fn new(dims: usize) -> Self {
         Self {
-            dt_pref: 0.0,
-            ds_pref: 0.0,
-            de_pref: 0.0,
+            pref: vec![0.0; dims],
             strength: 0.0,
+            context: vec![0.0; dims],
+            target: vec![0.0; dims],
+            count: 0,
+            valence: 0.0,
+            mean: vec![0.0; dims],
+            comoment: vec![0.0; dims * dims],
+            idle: 0,
+            replays: 0,
         }
     }
 
+    /// Preferred tension change
+    pub fn dt_pref(&self) -> f32 {
+        self.pref[TENSION]
+    }
+
+    /// Preferred stability change
+    pub fn ds_pref(&self) -> f32 {
+        self.pref[STABILITY]
+    }
+
+    /// Preferred energy change
+    pub fn de_pref(&self) -> f32 {
+        self.pref[ENERGY]
+    }
+
     /// How well this bias matches the current state change
-    pub fn similarity(&self, dt: f32, ds: f32, de: f32) -> f32 {
-        (self.dt_pref - dt).abs()
-            + (self.ds_pref - ds).abs()
-            + (self.de_pref - de).abs()
+    pub fn similarity(&self, delta: &[f32]) -> f32 {
+        self.similarity_with(delta, &L1)
     }
 
-    pub fn reinforce(&mut self, dt: f32, ds: f32, de: f32) {
-        self.dt_pref += dt * 0.1;
-        self.ds_pref += ds * 0.1;
-        self.de_pref += de * 0.1;
+    /// `similarity` under any metric (lower = more similar)
+    pub fn similarity_with(&self, delta: &[f32], metric: &dyn Metric) -> f32 {
+        metric.distance(&self.pref, delta)
+    }
 
-        self.strength += 0.05;
+    pub fn reinforce(&mut self, delta: &[f32]) {
+        self.reinforce_with(delta, 0.1, 0.05);
+    }
+
+    /// Reinforce with explicit learning rate and strength step (see `Genome`)
+    pub fn reinforce_with(&mut self, delta: &[f32], rate: f32, step: f32) {
+        for (p, d) in self.pref.iter_mut().zip(delta.iter()) {
+            *p += d * rate;
+        }
+
+        self.strength += step;
         self.strength = self.strength.clamp(0.0, 1.0);
+
+        self.count += 1;
+        self.idle = 0;
+
+        // Welford update of mean + co-moment
+        let n = self.count as f32;
+        let old: Vec<f32> = delta.iter().zip(self.mean.iter()).map(|(d, m)| d - m).collect();
+        for (m, o) in self.mean.iter_mut().zip(old.iter()) {
+            *m += o / n;
+        }
+        let new: Vec<f32> = delta.iter().zip(self.mean.iter()).map(|(d, m)| d - m).collect();
+
+        let dims = self.mean.len();
+        for (row, o) in self.comoment.chunks_mut(dims.max(1)).zip(old.iter()) {
+            for (c, n) in row.iter_mut().zip(new.iter()) {
+                *c += o * n;
+            }
+        }
+    }
+
+    /// Sample covariance between two dimensions of absorbed deltas
+    pub fn covariance(&self, i: usize, j: usize) -> f32 {
+        if self.count < 2 {
+            return 0.0;
+        }
+        self.comoment[i * self.mean.len() + j] / (self.count - 1) as f32
+    }
+
+    /// Per-dimension variance of absorbed deltas
+    pub fn variance(&self) -> Vec<f32> {
+        (0..self.mean.len()).map(|i| self.covariance(i, i)).collect()
+    }
+
+    /// Trace of the covariance (how blurred the basin is)
+    pub fn total_variance(&self) -> f32 {
+        self.variance().iter().sum()
+    }
+
+    /// 0..1: many consistent experiences -> high, few or blurred -> low
+    pub fn confidence(&self) -> f32 {
+        let n = self.count as f32;
+        let size: f32 = self.mean.iter().map(|m| m.abs()).sum();
+        let spread = self.total_variance().sqrt();
+
+        (n / (n + 1.0)) / (1.0 + spread / (size + f32::EPSILON))
+    }
+
+    pub fn should_split(&self, s: &Splitting) -> bool {
+        self.count >= s.min_count && self.total_variance() > s.variance_threshold
+    }
+
+    /// Two children on either side of the principal axis of variance.
+    /// Each gets half the strength and history; spread starts afresh.
+    pub fn split(&self) -> (BiasField, BiasField) {
+        let (axis, var) = self.principal_axis();
+        let sigma = var.max(0.0).sqrt();
+
+        let child = |sign: f32| {
+            let mut c = self.clone();
+            for ((p, m), u) in c.pref.iter_mut().zip(c.mean.iter_mut()).zip(axis.iter()) {
+                *p += sign * sigma * u;
+                *m += sign * sigma * u;
+            }
+            c.comoment.iter_mut().for_each(|v| *v = 0.0);
+            c.strength = self.strength * 0.5;
+            c.count = (self.count / 2).max(1);
+            c
+        };
+
+        (child(1.0), child(-1.0))
+    }
+
+    /// Largest eigenvector of the covariance (power iteration) + its variance
+    fn principal_axis(&self) -> (Vec<f32>, f32) {
+        let dims = self.mean.len();
+        let cov: Vec<f32> = (0..dims * dims).map(|k| self.covariance(k / dims, k % dims)).collect();
+
+        // Start on the most variable dimension
+        let var = self.variance();
+        let start = (0..dims).max_by(|a, b| var[*a].total_cmp(&var[*b])).unwrap_or(0);
+        let mut v = vec![0.0; dims];
+        if dims > 0 {
+            v[start] = 1.0;
+        }
+
+        let mut lambda = 0.0;
+        for _ in 0..50 {
+            let w: Vec<f32> = (0..dims)
+                .map(|i| (0..dims).map(|j| cov[i * dims + j] * v[j]).sum())
+                .collect();
+            let norm = w.iter().map(|x| x * x).sum::<f32>().sqrt();
+            if norm <= f32::EPSILON {
+                break;
+            }
+            lambda = norm;
+            v = w.iter().map(|x| x / norm).collect();
+        }
+
+        (v, lambda)
+    }
+
+    /// Pull the context centroid toward `state` (running mean over reinforcements)
+    pub fn remember_context(&mut self, state: &[f32]) {
+        let n = self.count.max(1) as f32;
+        for (c, x) in self.context.iter_mut().zip(state.iter()) {
+            *c += (x - *c) / n;
+        }
+    }
+
+    /// Pull the basin target toward `state` (running mean over reinforcements)
+    pub fn remember_target(&mut self, state: &[f32]) {
+        let n = self.count.max(1) as f32;
+        for (t, x) in self.target.iter_mut().zip(state.iter()) {
+            *t += (x - *t) / n;
+        }
+    }
+
+    /// Fold one experience's valence into the running mean
+    pub fn remember_valence(&mut self, valence: f32) {
+        let n = self.count.max(1) as f32;
+        self.valence += (valence - self.valence) / n;
+    }
+
+    /// Signed pull toward (> 0) or away from (< 0) what carved this basin
+    pub fn tendency(&self) -> f32 {
+        self.valence * self.strength
+    }
+
+    /// State change caused by replaying this bias once
+    pub fn replay_push(&self, state: &[f32], semantics: BiasSemantics, gain: f32) -> Vec<f32> {
+        match semantics {
+            BiasSemantics::Direction => self.pref.iter().map(|p| p * self.strength * gain).collect(),
+            BiasSemantics::Attractor { pull } => self
+                .target
+                .iter()
+                .zip(state.iter())
+                .map(|(t, x)| (t - x) * self.strength * pull)
+                .collect(),
+        }
+    }
+
+    /// How close `state` is to where this bias lives (1 = right here, -> 0 far away)
+    pub fn proximity(&self, state: &[f32], width: f32) -> f32 {
+        self.proximity_with(state, width, &L1)
+    }
+
+    /// `proximity` under any metric
+    pub fn proximity_with(&self, state: &[f32], width: f32, metric: &dyn Metric) -> f32 {
+        let dist = metric.distance(&self.context, state);
+        (-dist / width.max(f32::EPSILON)).exp()
     }
 
     pub fn decay(&mut self) {
-        self.strength *= 0.995; // slow forgetting
+        self.decay_by(0.995); // slow forgetting
+    }
+
+    pub fn decay_by(&mut self, factor: f32) {
+        self.strength *= factor;
     }
 
 
      /// Distance between two biases (structural similarity)
     pub fn distance(&self, other: &BiasField) -> f32 {
-        (self.dt_pref - other.dt_pref).abs()
-            + (self.ds_pref - other.ds_pref).abs()
-            + (self.de_pref - other.de_pref).abs()
+        self.distance_with(other, &L1)
+    }
+
+    /// `distance` under any metric
+    pub fn distance_with(&self, other: &BiasField, metric: &dyn Metric) -> f32 {
+        metric.distance(&self.pref, &other.pref)
     }
 
     /// Merge another bias into this one
@@ -54,15 +306,44 @@ impl BiasField {
         // Weighted average based on strength
         let total = self.strength + other.strength;
         if total > 0.0 {
-            self.dt_pref =
-                (self.dt_pref * self.strength + other.dt_pref * other.strength) / total;
-            self.ds_pref =
-                (self.ds_pref * self.strength + other.ds_pref * other.strength) / total;
-            self.de_pref =
-                (self.de_pref * self.strength + other.de_pref * other.strength) / total;
+            for (p, o) in self.pref.iter_mut().zip(other.pref.iter()) {
+                *p = (*p * self.strength + o * other.strength) / total;
+            }
+            for (c, o) in self.context.iter_mut().zip(other.context.iter()) {
+                *c = (*c * self.strength + o * other.strength) / total;
+            }
+            for (t, o) in self.target.iter_mut().zip(other.target.iter()) {
+                *t = (*t * self.strength + o * other.strength) / total;
+            }
+            self.valence = (self.valence * self.strength + other.valence * other.strength) / total;
         }
 
+        // Parallel Welford: combine mean + co-moment
+        let (na, nb) = (self.count as f32, other.count as f32);
+        if na + nb > 0.0 {
+            let dims = self.mean.len();
+            let d: Vec<f32> = other.mean.iter().zip(self.mean.iter()).map(|(b, a)| b - a).collect();
+            let rows = self.comoment.chunks_mut(dims.max(1)).zip(other.comoment.chunks(dims.max(1)));
+            for ((row, other_row), di) in rows.zip(d.iter()) {
+                for ((c, o), dj) in row.iter_mut().zip(other_row.iter()).zip(d.iter()) {
+                    *c += o + di * dj * na * nb / (na + nb);
+                }
+            }
+            for (m, di) in self.mean.iter_mut().zip(d.iter()) {
+                *m += di * nb / (na + nb);
+            }
+        }
+
+        self.count += other.count;
+        self.idle = self.idle.min(other.idle);
+        self.replays += other.replays;
+
         // Basin deepens
         self.strength = (self.strength + other.strength).clamp(0.0, 1.0);
     }
 }
+
+/// Strongest basin, if any
+pub fn dominant(biases: &[BiasField]) -> Option<&BiasField> {
+    biases.iter().max_by(|a, b| a.strength.total_cmp(&b.strength))
+}
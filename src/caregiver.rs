@@ -0,0 +1,227 @@
+// caregiver.rs
+//
+// Caregiver (CO-REGULATION)
+// -------------------------
+// Homeostasis only calms a newborn that is already calm. Real
+// newborns are regulated from outside: someone notices the distress,
+// comes (or not), picks them up and holds them.
+//
+// A caregiver is an `Environment`:
+//
+//     signal  : a cry, or visible tension above a trigger
+//     respond : with some probability, after some latency
+//     soothe  : one comforting touch (learned like any experience)
+//               + a few ticks of holding (external regulation
+//                 pulling tension down and stability up)
+//
+// Profiles differ in responsiveness, latency and consistency;
+// `reactive` is the plain "comes when it cries" caregiver.
+// Over many episodes the newborn's basins and plasticity are
+// shaped by who looked after it.
+
+use crate::behavior::{Action, Behavior};
+use crate::disturbance::{Disturbance, Modality};
+use crate::environment::Environment;
+use crate::rng::SplitMix64;
+use crate::state::{Snapshot, StateVector, STABILITY, TENSION};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaregiverProfile {
+    /// Chance of answering a given signal
+    pub responsiveness: f32,
+
+    /// Usual ticks between signal and response
+    pub latency: u32,
+
+    /// 0..1: how reliably the response is timely and gentle
+    pub consistency: f32,
+
+    /// Visible tension that counts as a signal even without crying
+    /// (INFINITY = only cries are noticed)
+    pub tension_trigger: f32,
+
+    /// Fraction of tension removed per tick of holding
+    pub soothing: f32,
+
+    /// Fraction of missing stability added per tick of holding
+    pub support: f32,
+
+    /// Ticks of holding per response
+    pub hold: u32,
+}
+
+impl CaregiverProfile {
+    /// Notices early, comes quickly, always gentle
+    pub fn sensitive() -> Self {
+        Self {
+            responsiveness: 0.95,
+            latency: 1,
+            consistency: 0.95,
+            tension_trigger: 0.8,
+            soothing: 0.15,
+            support: 0.02,
+            hold: 5,
+        }
+    }
+
+    /// Sometimes there, sometimes late, sometimes rough
+    pub fn inconsistent() -> Self {
+        Self {
+            responsiveness: 0.5,
+            latency: 4,
+            consistency: 0.4,
+            tension_trigger: f32::INFINITY,
+            soothing: 0.1,
+            support: 0.01,
+            hold: 3,
+        }
+    }
+
+    /// Always comes for a cry after exactly `latency` ticks,
+    /// gives one comforting touch and never holds
+    pub fn reactive(latency: u32) -> Self {
+        Self {
+            responsiveness: 1.0,
+            latency,
+            consistency: 1.0,
+            tension_trigger: f32::INFINITY,
+            soothing: 0.0,
+            support: 0.0,
+            hold: 0,
+        }
+    }
+
+    /// Rarely comes, and only after a long wait
+    pub fn neglectful() -> Self {
+        Self {
+            responsiveness: 0.1,
+            latency: 15,
+            consistency: 0.5,
+            tension_trigger: f32::INFINITY,
+            soothing: 0.05,
+            support: 0.0,
+            hold: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Caregiver {
+    pub profile: CaregiverProfile,
+
+    /// Signals answered / ignored so far
+    pub responses: u32,
+    pub ignored: u32,
+
+    /// Ticks until the pending response arrives (None = nobody coming)
+    pending: Option<u32>,
+
+    /// Whether the pending response will be gentle
+    gentle: bool,
+
+    /// Ticks of holding left
+    holding: u32,
+
+    rng: SplitMix64,
+}
+
+impl Caregiver {
+    pub fn new(profile: CaregiverProfile, seed: u64) -> Self {
+        Self {
+            profile,
+            responses: 0,
+            ignored: 0,
+            pending: None,
+            gentle: true,
+            holding: 0,
+            rng: SplitMix64::new(seed),
+        }
+    }
+
+    pub fn sensitive(seed: u64) -> Self {
+        Self::new(CaregiverProfile::sensitive(), seed)
+    }
+
+    pub fn inconsistent(seed: u64) -> Self {
+        Self::new(CaregiverProfile::inconsistent(), seed)
+    }
+
+    pub fn neglectful(seed: u64) -> Self {
+        Self::new(CaregiverProfile::neglectful(), seed)
+    }
+
+    /// Deterministic, so no seed is needed
+    pub fn reactive(latency: u32) -> Self {
+        Self::new(CaregiverProfile::reactive(latency), 0)
+    }
+
+    /// Currently holding the newborn
+    pub fn is_holding(&self) -> bool {
+        self.holding > 0
+    }
+
+    fn signalled(&self, newborn: &Snapshot, action: Option<&Action>) -> bool {
+        let cried = action.is_some_and(|a| a.behavior == Behavior::Cry);
+        cried || newborn.tension() > self.profile.tension_trigger
+    }
+
+    /// Decide whether and when to come
+    fn notice(&mut self) {
+        let p = &self.profile;
+        if self.rng.next_f32() >= p.responsiveness {
+            self.ignored += 1;
+            return;
+        }
+        self.responses += 1;
+
+        // Inconsistent care: late and rough
+        self.gentle = self.rng.next_f32() < p.consistency;
+        let latency = if self.gentle {
+            p.latency
+        } else {
+            p.latency * 2 + self.rng.below(p.latency as u64 + 1) as u32
+        };
+        self.pending = Some(latency);
+    }
+
+    /// What the arrival feels like to the newborn
+    fn touch(&self) -> Disturbance {
+        let touch = if self.gentle {
+            Disturbance::new(0.4, 2.0, 0.1).with_valence(1.0)
+        } else {
+            Disturbance::new(0.5, 0.5, 0.6).with_valence(-0.3)
+        };
+        touch.with_modality(Modality::Touch)
+    }
+}
+
+impl Environment for Caregiver {
+    fn step(&mut self, newborn: &Snapshot, action: Option<&Action>) -> Vec<Disturbance> {
+        if self.pending.is_none() && !self.is_holding() && self.signalled(newborn, action) {
+            self.notice();
+        }
+
+        match self.pending {
+            Some(0) => {
+                self.pending = None;
+                self.holding = if self.gentle { self.profile.hold } else { 0 };
+                vec![self.touch()]
+            }
+            Some(t) => {
+                self.pending = Some(t - 1);
+                Vec::new()
+            }
+            None => Vec::new(),
+        }
+    }
+
+    fn regulate(&mut self, state: &mut StateVector) {
+        if self.holding == 0 {
+            return;
+        }
+        self.holding -= 1;
+
+        state[TENSION] -= state[TENSION] * self.profile.soothing;
+        state[STABILITY] += (1.0 - state[STABILITY]) * self.profile.support;
+    }
+}
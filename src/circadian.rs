@@ -0,0 +1,136 @@
+// circadian.rs
+//
+// Circadian clock + fatigue (DAY / NIGHT STRUCTURE)
+// -------------------------------------------------
+// An internal oscillator with its own clock, advanced exactly once
+// per tick of simulated time (however busy the tick was):
+//
+//     drive = sin(2π (clock + phase) / period)   +1 midday, -1 midnight
+//
+// By day baseline energy is higher and shocks land softer; by
+// night energy sinks, shocks land harder and sleep pressure builds.
+// Fatigue rises with every experience and only drains with rest,
+// fastest during sleep.
+
+use std::f32::consts::TAU;
+
+use crate::plasticity::Plasticity;
+
+#[derive(Debug, Clone)]
+pub struct Circadian {
+    /// Steps per full day
+    pub period: f32,
+
+    /// Offset in steps (period / 4 = born at midday)
+    pub phase: f32,
+
+    /// Ticks elapsed since the clock started
+    pub clock: u64,
+
+    /// Resting energy at the average time of day
+    pub energy_baseline: f32,
+
+    /// How far resting energy swings around the baseline
+    pub energy_amplitude: f32,
+
+    /// Fraction of the gap to resting energy closed per thinking tick
+    pub energy_pull: f32,
+
+    /// Fraction by which shock sensitivity swings (higher at night)
+    pub sensitivity_amplitude: f32,
+
+    /// Accumulated tiredness, 0..1
+    pub fatigue: f32,
+
+    /// Fatigue added per unit disturbance intensity
+    pub fatigue_per_activity: f32,
+
+    /// Fraction of fatigue drained per awake tick
+    pub fatigue_recovery: f32,
+
+    /// Fraction of fatigue drained per tick of sleep
+    pub sleep_recovery: f32,
+
+    /// Extra shock sensitivity at full fatigue
+    pub fatigue_sensitivity: f32,
+}
+
+impl Circadian {
+    pub fn new(period: f32, phase: f32) -> Self {
+        Self {
+            period,
+            phase,
+            ..Self::default()
+        }
+    }
+
+    /// One tick of simulated time
+    pub fn advance(&mut self) {
+        self.clock += 1;
+    }
+
+    /// +1 midday .. -1 midnight
+    pub fn drive(&self) -> f32 {
+        (TAU * (self.clock as f32 + self.phase) / self.period.max(1.0)).sin()
+    }
+
+    /// Energy the body settles toward right now
+    pub fn resting_energy(&self) -> f32 {
+        self.energy_baseline + self.energy_amplitude * self.drive()
+    }
+
+    /// Energy nudge for one awake thinking tick
+    pub fn energy_drift(&self, energy: f32) -> f32 {
+        (self.resting_energy() - energy) * self.energy_pull
+    }
+
+    /// 0..1: how strongly the body wants to sleep
+    pub fn sleep_pressure(&self) -> f32 {
+        let night = (1.0 - self.drive()) * 0.5;
+        (0.5 * night + 0.5 * self.fatigue).clamp(0.0, 1.0)
+    }
+
+    /// An experience of `intensity` tires the body
+    pub fn exert(&mut self, intensity: f32) {
+        self.fatigue = (self.fatigue + intensity * self.fatigue_per_activity).min(1.0);
+    }
+
+    /// One awake tick
+    pub fn rest(&mut self) {
+        self.fatigue -= self.fatigue * self.fatigue_recovery;
+    }
+
+    /// One tick of sleep
+    pub fn sleep(&mut self) {
+        self.fatigue -= self.fatigue * self.sleep_recovery;
+    }
+
+    /// Reaction rates at this time of day and tiredness
+    pub fn modulate(&self, p: Plasticity) -> Plasticity {
+        let clock = 1.0 - self.sensitivity_amplitude * self.drive();
+        let tired = 1.0 + self.fatigue * self.fatigue_sensitivity;
+        Plasticity {
+            shock_sensitivity: p.shock_sensitivity * clock * tired,
+            ..p
+        }
+    }
+}
+
+impl Default for Circadian {
+    fn default() -> Self {
+        Self {
+            period: 240.0,
+            phase: 0.0,
+            clock: 0,
+            energy_baseline: 0.5,
+            energy_amplitude: 0.15,
+            energy_pull: 0.05,
+            sensitivity_amplitude: 0.3,
+            fatigue: 0.0,
+            fatigue_per_activity: 0.05,
+            fatigue_recovery: 0.005,
+            sleep_recovery: 0.1,
+            fatigue_sensitivity: 0.5,
+        }
+    }
+}
@@ -0,0 +1,194 @@
+// clustering.rs
+//
+// Basin clustering (STRUCTURAL CONSOLIDATION)
+// -------------------------------------------
+// Agglomerative merge: repeatedly merge the globally closest
+// pair of basins until no pair is closer than the threshold.
+//
+// Always merging the closest pair first makes the result
+// independent of the order in which basins were created.
+// Cluster distances are updated in place (Lance-Williams),
+// and only pairs under the threshold are ever queued, so it
+// stays cheap for thousands of basins.
+//
+// Awake, only one basin moves per experience, so `merge_into`
+// compares just that basin against the rest (O(n)); the full
+// pass runs during sleep.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+use crate::bias::BiasField;
+use crate::metric::Metric;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Linkage {
+    /// Closest members
+    Single,
+    /// Farthest members
+    Complete,
+    /// Mean member distance (UPGMA)
+    Average,
+    /// Distance between merged basin directions
+    Centroid,
+}
+
+#[derive(Debug, Clone)]
+pub struct Clustering {
+    pub linkage: Linkage,
+
+    /// Max distance for two basins to be the same basin
+    pub merge_distance: f32,
+
+    /// Clustering runs once there are more biases than this
+    pub trigger: usize,
+}
+
+impl Clustering {
+    pub fn new(merge_distance: f32, trigger: usize) -> Self {
+        Self {
+            linkage: Linkage::Centroid,
+            merge_distance,
+            trigger,
+        }
+    }
+
+    /// Merge basins in place; returns how many merges happened
+    pub fn cluster(&self, biases: &mut Vec<BiasField>, metric: &dyn Metric) -> usize {
+        let n = biases.len();
+        if n < 2 {
+            return 0;
+        }
+
+        // Pairwise distances (row-major, only i < j is used)
+        let mut dist = vec![0.0f32; n * n];
+        let mut heap = BinaryHeap::new();
+
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let d = biases[i].distance_with(&biases[j], metric);
+                dist[i * n + j] = d;
+                dist[j * n + i] = d;
+                if d < self.merge_distance {
+                    heap.push(Reverse(Pair { d, i, j, vi: 0, vj: 0 }));
+                }
+            }
+        }
+
+        let mut alive = vec![true; n];
+        let mut version = vec![0u32; n];
+        let mut members = vec![1.0f32; n];
+        let mut merges = 0;
+
+        while let Some(Reverse(p)) = heap.pop() {
+            // Stale entry: one side merged away or changed since queued
+            if !alive[p.i] || !alive[p.j] || version[p.i] != p.vi || version[p.j] != p.vj {
+                continue;
+            }
+
+            let (i, j) = (p.i, p.j);
+            let other = biases[j].clone();
+            biases[i].merge(&other);
+            alive[j] = false;
+            version[i] += 1;
+            merges += 1;
+
+            for k in (0..n).filter(|&k| alive[k] && k != i) {
+                let d = match self.linkage {
+                    Linkage::Single => dist[k * n + i].min(dist[k * n + j]),
+                    Linkage::Complete => dist[k * n + i].max(dist[k * n + j]),
+                    Linkage::Average => {
+                        (members[i] * dist[k * n + i] + members[j] * dist[k * n + j])
+                            / (members[i] + members[j])
+                    }
+                    Linkage::Centroid => biases[k].distance_with(&biases[i], metric),
+                };
+                dist[k * n + i] = d;
+                dist[i * n + k] = d;
+
+                if d < self.merge_distance {
+                    let (a, b) = (i.min(k), i.max(k));
+                    heap.push(Reverse(Pair {
+                        d,
+                        i: a,
+                        j: b,
+                        vi: version[a],
+                        vj: version[b],
+                    }));
+                }
+            }
+            members[i] += members[j];
+        }
+
+        let mut k = 0;
+        biases.retain(|_| {
+            k += 1;
+            alive[k - 1]
+        });
+
+        merges
+    }
+
+    /// Merge every basin closer than `merge_distance` to `biases[i]`
+    /// into it, closest first. Distances are between merged basin
+    /// directions whatever the linkage. Returns how many merges happened
+    pub fn merge_into(&self, biases: &mut Vec<BiasField>, mut i: usize, metric: &dyn Metric) -> usize {
+        let mut merges = 0;
+
+        while i < biases.len() {
+            let nearest = biases
+                .iter()
+                .enumerate()
+                .filter(|(k, _)| *k != i)
+                .map(|(k, b)| (k, biases[i].distance_with(b, metric)))
+                .filter(|(_, d)| *d < self.merge_distance)
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+
+            let Some((k, _)) = nearest else {
+                break;
+            };
+
+            let other = biases.remove(k);
+            if k < i {
+                i -= 1;
+            }
+            biases[i].merge(&other);
+            merges += 1;
+        }
+
+        merges
+    }
+}
+
+/// Queued candidate merge
+#[derive(Debug, Clone, Copy)]
+struct Pair {
+    d: f32,
+    i: usize,
+    j: usize,
+    vi: u32,
+    vj: u32,
+}
+
+impl PartialEq for Pair {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Pair {}
+
+impl PartialOrd for Pair {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Pair {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.d
+            .total_cmp(&other.d)
+            .then(self.i.cmp(&other.i))
+            .then(self.j.cmp(&other.j))
+    }
+}
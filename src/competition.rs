@@ -0,0 +1,150 @@
+// competition.rs
+//
+// Bias competition
+// ----------------
+// Which basin wins when several could absorb an experience
+// (reinforcement) or drive thinking (replay)?
+//
+// - winner-take-all : sharpest, the original newborn
+// - softmax         : soft, temperature controls sharpness
+// - proportional    : sample in proportion to fitness
+//
+// Lateral inhibition lets similar basins suppress each other
+// before the competition runs.
+
+use crate::bias::BiasField;
+use crate::metric::Metric;
+use crate::rng::SplitMix64;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompetitionMode {
+    WinnerTakeAll,
+    Softmax { temperature: f32 },
+    Proportional,
+}
+
+#[derive(Debug, Clone)]
+pub struct BiasCompetition {
+    pub mode: CompetitionMode,
+
+    /// How strongly neighbours suppress each other (0 = off)
+    pub inhibition: f32,
+
+    /// Distance scale over which inhibition acts
+    pub inhibition_width: f32,
+
+    rng: SplitMix64,
+}
+
+impl BiasCompetition {
+    pub fn new(mode: CompetitionMode, seed: u64) -> Self {
+        Self {
+            mode,
+            inhibition: 0.0,
+            inhibition_width: 0.1,
+            rng: SplitMix64::new(seed),
+        }
+    }
+
+    pub fn with_inhibition(mut self, inhibition: f32, width: f32) -> Self {
+        self.inhibition = inhibition;
+        self.inhibition_width = width;
+        self
+    }
+
+    /// Subtract activity of similar neighbours from each fitness;
+    /// similarity is measured with the brain's `metric`
+    pub fn inhibit(&self, fitness: &mut [f32], biases: &[&BiasField], metric: &dyn Metric) {
+        if self.inhibition <= 0.0 {
+            return;
+        }
+
+        let raw = fitness.to_vec();
+        for (i, f) in fitness.iter_mut().enumerate() {
+            // Excluded entries stay excluded
+            if !f.is_finite() {
+                continue;
+            }
+
+            let pressure: f32 = raw
+                .iter()
+                .enumerate()
+                .filter(|(j, fj)| *j != i && fj.is_finite())
+                .map(|(j, fj)| {
+                    let closeness = (-biases[i].distance_with(biases[j], metric) / self.inhibition_width).exp();
+                    fj.max(0.0) * closeness
+                })
+                .sum();
+            *f = (*f - self.inhibition * pressure).max(0.0);
+        }
+    }
+
+    /// Pick a winner (higher fitness = better).
+    /// Non-finite fitness never wins; ties go to the earliest.
+    /// Only an empty field of valid candidates yields None.
+    pub fn select(&mut self, fitness: &[f32]) -> Option<usize> {
+        let valid = |f: &f32| f.is_finite();
+
+        match self.mode {
+            CompetitionMode::WinnerTakeAll => best(fitness),
+            CompetitionMode::Softmax { temperature } => {
+                let t = temperature.max(f32::EPSILON);
+                let max = fitness.iter().copied().filter(valid).fold(f32::NEG_INFINITY, f32::max);
+                if !max.is_finite() {
+                    return None;
+                }
+                let weights: Vec<f32> = fitness
+                    .iter()
+                    .map(|f| if valid(f) { ((f - max) / t).exp() } else { 0.0 })
+                    .collect();
+                self.sample(&weights)
+            }
+            CompetitionMode::Proportional => {
+                let weights: Vec<f32> = fitness
+                    .iter()
+                    .map(|f| if valid(f) { f.max(0.0) } else { 0.0 })
+                    .collect();
+                // Candidates inhibited down to zero still beat nobody
+                self.sample(&weights).or_else(|| best(fitness))
+            }
+        }
+    }
+
+    /// Roulette-wheel sample from non-negative weights
+    fn sample(&mut self, weights: &[f32]) -> Option<usize> {
+        let total: f32 = weights.iter().sum();
+        if total.is_nan() || total <= 0.0 {
+            return None;
+        }
+
+        let mut r = self.rng.next_f32() * total;
+        for (i, w) in weights.iter().enumerate() {
+            if *w > 0.0 {
+                if r < *w {
+                    return Some(i);
+                }
+                r -= w;
+            }
+        }
+
+        // Rounding: last non-zero entry
+        weights.iter().rposition(|w| *w > 0.0)
+    }
+}
+
+/// Highest finite fitness; ties go to the earliest
+fn best(fitness: &[f32]) -> Option<usize> {
+    let mut best: Option<(usize, f32)> = None;
+    for (i, f) in fitness.iter().enumerate().filter(|(_, f)| f.is_finite()) {
+        if best.is_none_or(|(_, b)| *f > b) {
+            best = Some((i, *f));
+        }
+    }
+    best.map(|(i, _)| i)
+}
+
+impl Default for BiasCompetition {
+    fn default() -> Self {
+        Self::new(CompetitionMode::WinnerTakeAll, 0)
+    }
+}
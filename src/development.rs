@@ -0,0 +1,122 @@
+// development.rs
+//
+// Development (AGE + CRITICAL PERIODS)
+// ------------------------------------
+// A newborn learns fast; an older brain learns slowly.
+// Age is counted in lived steps (experiences + thinking ticks).
+//
+// Every kind of learning gets a multiplier at a given age:
+//
+//     multiplier = age curve x every critical period that covers it
+//
+// Inside a critical period that learning is amplified; after it
+// closes, the learning is damped (hard to change).
+
+/// What a developmental schedule acts on
+#[derive(Debug, Clone, PartialEq)]
+pub enum Learning {
+    /// Bias reinforcement (learning rate + strength step)
+    Reinforcement,
+    /// One plasticity rule, by name
+    Rule(String),
+}
+
+/// Overall learning speed as a function of age
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AgeCurve {
+    /// Learns like a newborn forever
+    Constant,
+    /// Halves every `half_life` steps, never below `floor`
+    Exponential { half_life: f32, floor: f32 },
+    /// Falls linearly to `floor` at age `end`
+    Linear { end: u64, floor: f32 },
+}
+
+impl AgeCurve {
+    pub fn at(self, age: u64) -> f32 {
+        match self {
+            AgeCurve::Constant => 1.0,
+            AgeCurve::Exponential { half_life, floor } => {
+                let t = age as f32 / half_life.max(f32::EPSILON);
+                floor + (1.0 - floor) * 0.5f32.powf(t)
+            }
+            AgeCurve::Linear { end, floor } => {
+                let t = (age as f32 / end.max(1) as f32).min(1.0);
+                floor + (1.0 - floor) * (1.0 - t)
+            }
+        }
+    }
+}
+
+/// Window in which one kind of learning is amplified
+#[derive(Debug, Clone, PartialEq)]
+pub struct CriticalPeriod {
+    pub learning: Learning,
+
+    /// First and last age (inclusive) of the window
+    pub start: u64,
+    pub end: u64,
+
+    /// Multiplier while the window is open
+    pub gain: f32,
+
+    /// Multiplier once it has closed
+    pub after: f32,
+}
+
+impl CriticalPeriod {
+    pub fn new(learning: Learning, start: u64, end: u64, gain: f32, after: f32) -> Self {
+        Self {
+            learning,
+            start,
+            end,
+            gain,
+            after,
+        }
+    }
+
+    pub fn at(&self, age: u64) -> f32 {
+        if age < self.start {
+            1.0
+        } else if age <= self.end {
+            self.gain
+        } else {
+            self.after
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Development {
+    pub curve: AgeCurve,
+    pub critical_periods: Vec<CriticalPeriod>,
+}
+
+impl Development {
+    pub fn new(curve: AgeCurve) -> Self {
+        Self {
+            curve,
+            critical_periods: Vec::new(),
+        }
+    }
+
+    pub fn with_period(mut self, period: CriticalPeriod) -> Self {
+        self.critical_periods.push(period);
+        self
+    }
+
+    /// How strongly `learning` acts at `age` (1 = newborn)
+    pub fn multiplier(&self, learning: &Learning, age: u64) -> f32 {
+        self.critical_periods
+            .iter()
+            .filter(|p| &p.learning == learning)
+            .fold(self.curve.at(age), |m, p| m * p.at(age))
+    }
+}
+
+impl Default for Development {
+    /// Original newborn: no aging, no critical periods
+    fn default() -> Self {
+        Self::new(AgeCurve::Constant)
+    }
+}
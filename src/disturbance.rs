@@ -1,8 +1,26 @@
+use crate::habituation::fingerprint;
+
 #[derive(Debug, Clone)]
 pub struct Disturbance { //setting disturbance in gradient
     pub intensity: f32, // 0.0 -> 1.0
     pub duration: f32, //time unit
-    pub suddenness: f32 // 0.0 smooth, 1.0 shock
+    pub suddenness: f32, // 0.0 smooth, 1.0 shock
+    pub valence: f32, // -1.0 aversive, 0.0 neutral, 1.0 pleasant
+    pub modality: Option<Modality>, // which sense it arrived through
+    pub stimulus: Option<u64>, // identity, for per-stimulus habituation
+}
+
+/// Sensory channel a disturbance arrives through
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Modality {
+    Touch,
+    Sound,
+    Sight,
+    Taste,
+    Smell,
+    Temperature,
+    /// From inside the body (hunger, pain, ...)
+    Interoceptive,
 }
  impl Disturbance {
     pub fn new(intensity: f32, duration: f32, suddenness: f32) -> Self {
@@ -10,6 +28,51 @@ pub struct Disturbance { //setting disturbance in gradient
             intensity,  
             duration,   
             suddenness, 
+            valence: 0.0,
+            modality: None,
+            stimulus: None,
         }
     }
+
+    /// Same disturbance, felt as pleasant (> 0) or aversive (< 0)
+    pub fn with_valence(mut self, valence: f32) -> Self {
+        self.valence = valence.clamp(-1.0, 1.0);
+        self
+    }
+
+    pub fn with_modality(mut self, modality: Modality) -> Self {
+        self.modality = Some(modality);
+        self
+    }
+
+    /// Tag with a stimulus identity (same id = same thing again)
+    pub fn with_stimulus(mut self, id: u64) -> Self {
+        self.stimulus = Some(id);
+        self
+    }
+
+    /// Tag with an identity derived from a feature vector
+    pub fn with_fingerprint(self, features: &[f32]) -> Self {
+        self.with_stimulus(fingerprint(features))
+    }
+
+    /// Comfort = strong + pleasant
+    pub fn comfort(&self) -> f32 {
+        self.intensity * self.valence.max(0.0)
+    }
+
+    /// Harm = strong + aversive
+    pub fn harm(&self) -> f32 {
+        self.intensity * (-self.valence).max(0.0)
+    }
+
+    /// Shock = strong + sudden
+    pub fn shock(&self) -> f32 {
+        self.intensity * self.suddenness
+    }
+
+    /// Calm = long + predictable
+    pub fn calm(&self) -> f32 {
+        self.duration * (1.0 - self.suddenness)
+    }
  }
\ No newline at end of file
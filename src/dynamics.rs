@@ -0,0 +1,166 @@
+// dynamics.rs
+//
+// Continuous-time physiology
+// --------------------------
+// The instant model in `apply_disturbance` jumps the state.
+// Here the same physiology is written as RATES, so a
+// disturbance unfolds over its `duration` and can be
+// integrated with any step size.
+//
+// Away from the biological limits the rates are exactly the
+// instant model spread over the duration, so totals match it.
+// Only within the last `SATURATION_BAND` of a range do the
+// rates saturate (instead of being clipped).
+
+use crate::disturbance::Disturbance;
+use crate::genome::Genome;
+use crate::plasticity::Plasticity;
+use crate::state::{StateVector, ENERGY, STABILITY, TENSION};
+
+/// Fraction of a range, next to each limit, in which rates saturate
+pub const SATURATION_BAND: f32 = 0.1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Integrator {
+    /// First order, cheap
+    Euler,
+    /// Classic 4th order Runge-Kutta
+    Rk4,
+}
+
+/// How a disturbance is sliced in time
+#[derive(Debug, Clone, Copy)]
+pub struct TimeStep {
+    pub dt: f32,
+    pub integrator: Integrator,
+}
+
+impl Default for TimeStep {
+    fn default() -> Self {
+        Self {
+            dt: 0.05,
+            integrator: Integrator::Rk4,
+        }
+    }
+}
+
+impl TimeStep {
+    /// Smallest step honoured; smaller (or non-positive) `dt` is raised to it
+    pub const MIN_DT: f32 = 1e-4;
+
+    /// Number of equal steps covering `span` (at least one)
+    pub fn steps(&self, span: f32) -> usize {
+        let dt = self.dt.max(Self::MIN_DT);
+        (span / dt).ceil().max(1.0) as usize
+    }
+}
+
+/// How overlapping disturbances combine
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overlap {
+    /// Effects add up (noise + rocking = both)
+    Sum,
+    /// Strongest effect per dimension wins
+    Max,
+}
+
+/// 1 far from the upper limit, falling to 0 inside the saturation band
+fn headroom_up(x: f32, range: (f32, f32)) -> f32 {
+    saturate((range.1 - x) / (range.1 - range.0))
+}
+
+/// 1 far from the lower limit, falling to 0 inside the saturation band
+fn headroom_down(x: f32, range: (f32, f32)) -> f32 {
+    saturate((x - range.0) / (range.1 - range.0))
+}
+
+fn saturate(room: f32) -> f32 {
+    (room / SATURATION_BAND).clamp(0.0, 1.0)
+}
+
+/// d(state)/dt while `d` is acting
+/// (extra dimensions have no physiology here: rate 0)
+pub fn rates(s: &StateVector, d: &Disturbance, p: &Plasticity, g: &Genome) -> StateVector {
+    let duration = d.duration.max(f32::EPSILON);
+
+    // Shock is delivered spread over the duration
+    let shock_rate = d.shock() / duration;
+
+    // Calm accumulates per unit time (calm = duration * (1 - suddenness))
+    let calm_rate = 1.0 - d.suddenness;
+
+    let energy_rate = (d.intensity * p.energy_gain_rate
+        - d.shock() * p.energy_gain_rate * g.shock_energy_drain)
+        / duration;
+
+    // Valence is delivered spread over the duration too
+    let tension_rate = shock_rate * p.shock_sensitivity + d.harm() * g.harm_stress / duration;
+    let soothe_rate = d.comfort() * g.comfort_soothing / duration;
+    let energy_rate = energy_rate + d.comfort() * g.comfort_restoration / duration;
+
+    let dims = &g.dimensions;
+
+    let energy_room = if energy_rate >= 0.0 {
+        headroom_up(s[ENERGY], dims[ENERGY].range)
+    } else {
+        headroom_down(s[ENERGY], dims[ENERGY].range)
+    };
+
+    let mut r = StateVector::zeros(s.len());
+    r[TENSION] = tension_rate * headroom_up(s[TENSION], dims[TENSION].range)
+        - soothe_rate * headroom_down(s[TENSION], dims[TENSION].range);
+    r[STABILITY] = calm_rate * p.stability_gain_rate * headroom_up(s[STABILITY], dims[STABILITY].range);
+    r[ENERGY] = energy_rate * energy_room;
+    r
+}
+
+impl Integrator {
+    /// Advance `y` by `h` under `f`
+    pub fn step(self, y: &StateVector, h: f32, f: impl Fn(&StateVector) -> StateVector) -> StateVector {
+        match self {
+            Integrator::Euler => y.add_scaled(&f(y), h),
+            Integrator::Rk4 => {
+                let k1 = f(y);
+                let k2 = f(&y.add_scaled(&k1, h * 0.5));
+                let k3 = f(&y.add_scaled(&k2, h * 0.5));
+                let k4 = f(&y.add_scaled(&k3, h));
+
+                y.add_scaled(&k1, h / 6.0)
+                    .add_scaled(&k2, h / 3.0)
+                    .add_scaled(&k3, h / 3.0)
+                    .add_scaled(&k4, h / 6.0)
+            }
+        }
+    }
+}
+
+/// Rates of several disturbances acting together.
+/// Each entry carries a weight (fraction of the step it is active).
+pub fn combined_rates(
+    s: &StateVector,
+    active: &[(&Disturbance, f32)],
+    overlap: Overlap,
+    p: &Plasticity,
+    g: &Genome,
+) -> StateVector {
+    let mut acc = StateVector::zeros(s.len());
+
+    for (d, w) in active {
+        let r = rates(s, d, p, g);
+        match overlap {
+            Overlap::Sum => acc = acc.add_scaled(&r, *w),
+            Overlap::Max => {
+                for (a, x) in acc.iter_mut().zip(r.iter()) {
+                    *a = stronger(*a, x * w);
+                }
+            }
+        }
+    }
+
+    acc
+}
+
+/// Larger magnitude (sign preserved)
+fn stronger(a: f32, b: f32) -> f32 {
+    if b.abs() > a.abs() { b } else { a }
+}
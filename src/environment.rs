@@ -0,0 +1,230 @@
+// environment.rs
+//
+// Environment (CLOSED LOOP)
+// -------------------------
+// Instead of scripting every disturbance up front, an environment
+// watches the newborn each tick (its snapshot + what it just did)
+// and decides what happens next.
+//
+//     newborn --snapshot, action--> environment --disturbances--> newborn
+//
+// Ticks without disturbances are internal thinking.
+
+use std::fmt::Debug;
+
+use crate::behavior::Action;
+use crate::disturbance::Disturbance;
+use crate::isf::InternalStateField;
+use crate::rng::SplitMix64;
+use crate::state::{Snapshot, StateVector, StepReport};
+
+pub trait Environment: Debug {
+    /// Disturbances for this tick, given the newborn's last snapshot
+    /// and its last action (None before it ever acted)
+    fn step(&mut self, newborn: &Snapshot, action: Option<&Action>) -> Vec<Disturbance>;
+
+    /// External regulation of the newborn's state after this tick's
+    /// disturbances (someone holding it); nothing by default
+    fn regulate(&mut self, _state: &mut StateVector) {}
+}
+
+/// One tick of recorded history
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub tick: u32,
+
+    /// What the environment did
+    pub disturbances: Vec<Disturbance>,
+
+    /// What the newborn did in response (None = passive run)
+    pub action: Option<Action>,
+
+    pub report: StepReport,
+
+    /// State after the tick
+    pub snapshot: Snapshot,
+}
+
+/// Drives a newborn and an environment in a loop
+#[derive(Debug, Clone)]
+pub struct Runner {
+    /// Let the newborn act every tick (false = passive observer)
+    pub act: bool,
+}
+
+impl Runner {
+    pub fn new() -> Self {
+        Self { act: true }
+    }
+
+    /// The newborn only feels, never acts
+    pub fn passive() -> Self {
+        Self { act: false }
+    }
+
+    pub fn run(&self, isf: &mut InternalStateField, env: &mut dyn Environment, ticks: u32) -> Vec<Record> {
+        let mut history = Vec::with_capacity(ticks as usize);
+
+        for tick in 0..ticks {
+            let disturbances = env.step(&isf.snapshot(tick), isf.last_action.as_ref());
+
+            let mut report = StepReport::default();
+            if disturbances.is_empty() {
+                report.absorb(isf.internal_thinking_tick());
+            } else {
+                // All of this tick's disturbances share one lived step
+                for d in &disturbances {
+                    report.absorb(isf.feel(d));
+                }
+                isf.elapse(false);
+            }
+
+            isf.co_regulate(|state| env.regulate(state));
+
+            let action = if self.act { Some(isf.act()) } else { None };
+
+            let mut snapshot = isf.snapshot(tick);
+            snapshot.active_disturbances = disturbances.len();
+            snapshot.pruned = report.pruned;
+            snapshot.split = report.split;
+
+            history.push(Record {
+                tick,
+                disturbances,
+                action,
+                report,
+                snapshot,
+            });
+        }
+
+        history
+    }
+}
+
+impl Default for Runner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// --------------------------------------------------
+// BUILT-IN ENVIRONMENTS
+// --------------------------------------------------
+
+/// Fixed script, blind to the newborn: tick i gets `script[i]`
+#[derive(Debug, Clone)]
+pub struct Scripted {
+    pub script: Vec<Vec<Disturbance>>,
+    cursor: usize,
+}
+
+impl Scripted {
+    pub fn new(script: Vec<Vec<Disturbance>>) -> Self {
+        Self { script, cursor: 0 }
+    }
+
+    /// One disturbance per tick, then `rest` quiet ticks
+    pub fn sequence(disturbances: Vec<Disturbance>, rest: usize) -> Self {
+        let mut script: Vec<Vec<Disturbance>> = disturbances.into_iter().map(|d| vec![d]).collect();
+        script.extend(std::iter::repeat_n(Vec::new(), rest));
+        Self::new(script)
+    }
+
+    /// The birth scenario of main.rs: shock, two calm experiences, 100 ticks of thinking
+    pub fn birth() -> Self {
+        Self::sequence(
+            vec![
+                Disturbance::new(0.9, 0.1, 0.9),
+                Disturbance::new(0.3, 2.0, 0.1),
+                Disturbance::new(0.4, 1.5, 0.2),
+            ],
+            100,
+        )
+    }
+
+    pub fn len(&self) -> usize {
+        self.script.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.script.is_empty()
+    }
+}
+
+impl Environment for Scripted {
+    fn step(&mut self, _: &Snapshot, _: Option<&Action>) -> Vec<Disturbance> {
+        let out = self.script.get(self.cursor).cloned().unwrap_or_default();
+        self.cursor += 1;
+        out
+    }
+}
+
+/// Several environments acting at once (a world + a caregiver)
+#[derive(Debug, Default)]
+pub struct Combined {
+    pub parts: Vec<Box<dyn Environment>>,
+}
+
+impl Combined {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, env: impl Environment + 'static) -> Self {
+        self.parts.push(Box::new(env));
+        self
+    }
+}
+
+impl Environment for Combined {
+    fn step(&mut self, newborn: &Snapshot, action: Option<&Action>) -> Vec<Disturbance> {
+        self.parts.iter_mut().flat_map(|e| e.step(newborn, action)).collect()
+    }
+
+    fn regulate(&mut self, state: &mut StateVector) {
+        for e in self.parts.iter_mut() {
+            e.regulate(state);
+        }
+    }
+}
+
+/// Random world: each tick an event happens with some probability
+#[derive(Debug, Clone)]
+pub struct RandomEvents {
+    /// Chance of an event per tick
+    pub probability: f32,
+
+    /// Max intensity of an event
+    pub max_intensity: f32,
+
+    /// Max duration of an event
+    pub max_duration: f32,
+
+    rng: SplitMix64,
+}
+
+impl RandomEvents {
+    pub fn new(seed: u64, probability: f32) -> Self {
+        Self {
+            probability,
+            max_intensity: 1.0,
+            max_duration: 2.0,
+            rng: SplitMix64::new(seed),
+        }
+    }
+}
+
+impl Environment for RandomEvents {
+    fn step(&mut self, _: &Snapshot, _: Option<&Action>) -> Vec<Disturbance> {
+        if self.rng.next_f32() >= self.probability {
+            return Vec::new();
+        }
+
+        let intensity = self.rng.next_f32() * self.max_intensity;
+        let duration = self.rng.next_f32() * self.max_duration;
+        let suddenness = self.rng.next_f32();
+        let valence = self.rng.next_f32() * 2.0 - 1.0;
+
+        vec![Disturbance::new(intensity, duration, suddenness).with_valence(valence)]
+    }
+}
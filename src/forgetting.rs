@@ -0,0 +1,75 @@
+// forgetting.rs
+//
+// Forgetting (STRUCTURAL DECAY)
+// -----------------------------
+// Basins fade unless they are used. Forgetting can run after
+// every disturbance and/or every thinking tick; basins that
+// fade below the floor are pruned for good.
+
+use crate::bias::BiasField;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ForgettingCurve {
+    /// strength *= factor every step
+    Exponential { factor: f32 },
+    /// strength ~ (1 + idle)^-exponent since last reinforcement
+    PowerLaw { exponent: f32 },
+    /// Exponential, but replayed basins decay slower
+    UseDependent { factor: f32, protection: f32 },
+}
+
+impl ForgettingCurve {
+    pub fn apply(self, b: &mut BiasField) {
+        match self {
+            ForgettingCurve::Exponential { factor } => b.decay_by(factor),
+            ForgettingCurve::PowerLaw { exponent } => {
+                let t = b.idle as f32;
+                b.decay_by(((1.0 + t) / (2.0 + t)).powf(exponent));
+            }
+            ForgettingCurve::UseDependent { factor, protection } => {
+                let shield = 1.0 + protection * b.replays as f32;
+                b.decay_by(1.0 - (1.0 - factor) / shield);
+            }
+        }
+        b.idle += 1;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Forgetting {
+    /// Curve applied after each disturbance (None = no decay)
+    pub on_disturbance: Option<ForgettingCurve>,
+
+    /// Curve applied after each thinking tick (None = no decay)
+    pub on_tick: Option<ForgettingCurve>,
+
+    /// Basins weaker than this are removed (0 = never prune)
+    pub prune_floor: f32,
+}
+
+impl Forgetting {
+    /// Original newborn: slow exponential decay per disturbance only
+    pub fn per_disturbance(factor: f32) -> Self {
+        Self {
+            on_disturbance: Some(ForgettingCurve::Exponential { factor }),
+            on_tick: None,
+            prune_floor: 0.0,
+        }
+    }
+
+    /// Remove dead basins, returns how many were pruned
+    pub fn prune(&self, biases: &mut Vec<BiasField>) -> usize {
+        let before = biases.len();
+        if self.prune_floor > 0.0 {
+            biases.retain(|b| b.strength >= self.prune_floor);
+        }
+        before - biases.len()
+    }
+}
+
+/// Decay every bias with `curve`
+pub fn fade(curve: ForgettingCurve, biases: &mut [BiasField]) {
+    for b in biases.iter_mut() {
+        curve.apply(b);
+    }
+}
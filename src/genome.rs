@@ -0,0 +1,224 @@
+// genome.rs
+//
+// Physiology genome
+// -----------------
+// Every number that defines HOW a newborn reacts lives here.
+// Defaults = the original hard-coded newborn.
+//
+// Different temperaments = different genomes:
+//
+//     let jumpy = Genome { shock_sensitivity: 0.4, ..Genome::default() };
+//     let brain = InternalStateField::with_config(jumpy);
+//
+// Extra state dimensions are part of the genome too:
+//
+//     let hungry = Genome::default().with_dimension(Dimension::new("hunger", 0.0, (0.0, 1.0)));
+
+use crate::state::{newborn_dimensions, Dimension, StateVector};
+
+#[derive(Debug, Clone)]
+pub struct Genome {
+    // --------------------------------------------------
+    // BIRTH STATE + BIOLOGICAL LIMITS
+    // --------------------------------------------------
+
+    /// Named state dimensions (core triple first)
+    pub dimensions: Vec<Dimension>,
+
+    // --------------------------------------------------
+    // BIRTH PLASTICITY
+    // --------------------------------------------------
+    pub shock_sensitivity: f32,
+    pub stability_gain_rate: f32,
+    pub energy_gain_rate: f32,
+
+    /// Fraction of energy_gain_rate drained per unit shock
+    pub shock_energy_drain: f32,
+
+    // --------------------------------------------------
+    // VALENCE (PLEASANT VS AVERSIVE)
+    // --------------------------------------------------
+
+    /// Tension released per unit comfort
+    pub comfort_soothing: f32,
+
+    /// Energy restored per unit comfort
+    pub comfort_restoration: f32,
+
+    /// Tension added per unit harm
+    pub harm_stress: f32,
+
+    // --------------------------------------------------
+    // PLASTICITY LIMITS (min, max)
+    // --------------------------------------------------
+    pub shock_sensitivity_range: (f32, f32),
+
+    /// An installed sensitization rule may push shock_sensitivity past the range,
+    /// into the hypervigilant regime, but never past this
+    pub hypervigilance_limit: f32,
+    pub stability_gain_rate_range: (f32, f32),
+    pub energy_gain_rate_range: (f32, f32),
+
+    // --------------------------------------------------
+    // STRUCTURAL MEMORY
+    // --------------------------------------------------
+
+    /// Max similarity score for a delta to reinforce an existing bias
+    pub bias_match_threshold: f32,
+
+    /// How much of each delta a bias absorbs
+    pub bias_learning_rate: f32,
+
+    /// Strength added per reinforcement
+    pub bias_strength_step: f32,
+
+    /// Per-disturbance strength multiplier (slow forgetting)
+    pub bias_decay: f32,
+
+    /// Max distance for two biases to be the same basin
+    pub bias_merge_distance: f32,
+
+    /// Clustering runs once there are more biases than this
+    pub cluster_trigger: usize,
+
+    /// Gain of bias replay during thinking
+    pub bias_replay_gain: f32,
+
+    /// Gain of trace replay during thinking
+    pub trace_replay_gain: f32,
+
+    /// Each replay of the same episode is this much fainter
+    pub trace_replay_fade: f32,
+
+    // --------------------------------------------------
+    // PLASTICITY THRESHOLDS
+    // --------------------------------------------------
+
+    /// Desensitization: shock above this ...
+    pub desensitize_shock: f32,
+    /// ... while stability is above this
+    pub desensitize_stability: f32,
+    pub desensitize_factor: f32,
+
+    /// Sensitization: shock above this ...
+    pub sensitize_shock: f32,
+    /// ... while stability is below this ...
+    pub sensitize_stability: f32,
+    /// ... this many times in a row
+    pub sensitize_repeats: u32,
+    pub sensitize_factor: f32,
+
+    /// Sensitization recovery: calm above this, this many times in a row
+    pub sensitize_calm: f32,
+    pub sensitize_calm_needed: u32,
+    pub sensitize_recovery: f32,
+
+    /// Regulation learning: calm above this
+    pub calm_threshold: f32,
+    pub regulation_learning_factor: f32,
+
+    /// Energy efficiency: tension above this
+    pub overload_tension: f32,
+    pub energy_efficiency_factor: f32,
+
+    // --------------------------------------------------
+    // HOMEOSTASIS
+    // --------------------------------------------------
+
+    /// Below this tension, stability consolidates
+    pub calm_tension: f32,
+    pub stability_recovery: f32,
+
+    /// Comfortable energy band (low, high)
+    pub energy_band: (f32, f32),
+    pub energy_balance_step: f32,
+
+    // --------------------------------------------------
+    // SLEEP
+    // --------------------------------------------------
+
+    /// Energy restored per tick of sleep
+    pub sleep_energy_restore: f32,
+
+    /// Fraction of tension released per tick of sleep
+    pub sleep_tension_release: f32,
+}
+
+impl Default for Genome {
+    fn default() -> Self {
+        Self {
+            dimensions: newborn_dimensions(),
+
+            shock_sensitivity: 0.2,
+            stability_gain_rate: 0.05,
+            energy_gain_rate: 0.1,
+            shock_energy_drain: 0.5,
+
+            comfort_soothing: 0.1,
+            comfort_restoration: 0.05,
+            harm_stress: 0.1,
+
+            shock_sensitivity_range: (0.05, 0.5),
+            hypervigilance_limit: 0.8,
+            stability_gain_rate_range: (0.01, 0.2),
+            energy_gain_rate_range: (0.05, 0.3),
+
+            bias_match_threshold: 0.2,
+            bias_learning_rate: 0.1,
+            bias_strength_step: 0.05,
+            bias_decay: 0.995,
+            bias_merge_distance: 0.1,
+            cluster_trigger: 5,
+            bias_replay_gain: 0.05,
+            trace_replay_gain: 0.2,
+            trace_replay_fade: 0.5,
+
+            desensitize_shock: 0.3,
+            desensitize_stability: 0.6,
+            desensitize_factor: 0.98,
+
+            sensitize_shock: 0.3,
+            sensitize_stability: 0.3,
+            sensitize_repeats: 2,
+            sensitize_factor: 1.05,
+            sensitize_calm: 1.0,
+            sensitize_calm_needed: 3,
+            sensitize_recovery: 0.9,
+
+            calm_threshold: 1.0,
+            regulation_learning_factor: 1.02,
+
+            overload_tension: 1.0,
+            energy_efficiency_factor: 0.99,
+
+            calm_tension: 0.4,
+            stability_recovery: 0.01,
+            energy_band: (0.4, 0.6),
+            energy_balance_step: 0.01,
+
+            sleep_energy_restore: 0.02,
+            sleep_tension_release: 0.05,
+        }
+    }
+}
+
+impl Genome {
+    /// Add an extra state dimension (hunger, warmth, ...)
+    pub fn with_dimension(mut self, dim: Dimension) -> Self {
+        self.dimensions.push(dim);
+        self
+    }
+
+    pub fn dimension_index(&self, name: &str) -> Option<usize> {
+        self.dimensions.iter().position(|d| d.name == name)
+    }
+
+    pub fn birth_state(&self) -> StateVector {
+        StateVector(self.dimensions.iter().map(|d| d.birth).collect())
+    }
+}
+
+/// Clamp into a (min, max) genome range
+pub fn limit(value: f32, range: (f32, f32)) -> f32 {
+    value.clamp(range.0, range.1)
+}
@@ -0,0 +1,117 @@
+// habituation.rs
+//
+// Habituation (PER-STIMULUS)
+// --------------------------
+// The door slamming for the tenth time barely registers, but a new
+// alarm still hits at full force. Every identified stimulus keeps its
+// own response gain:
+//
+//     repetition  -> gain drops toward the floor
+//     novelty     -> habituated gains bounce back (dishabituation)
+//     time        -> gains slowly recover toward 1
+//
+// Disturbances without a stimulus id are always felt at full gain.
+
+use std::collections::HashMap;
+
+use crate::disturbance::Disturbance;
+
+#[derive(Debug, Clone)]
+pub struct Habituation {
+    /// Fraction of the remaining response lost per repetition
+    pub rate: f32,
+
+    /// Response never drops below this
+    pub floor: f32,
+
+    /// Fraction of lost response regained per tick
+    pub recovery: f32,
+
+    /// Fraction of lost response a novel stimulus restores
+    pub dishabituation: f32,
+
+    gains: HashMap<u64, f32>,
+}
+
+impl Habituation {
+    pub fn new(rate: f32, floor: f32, recovery: f32, dishabituation: f32) -> Self {
+        Self {
+            rate,
+            floor,
+            recovery,
+            dishabituation,
+            gains: HashMap::new(),
+        }
+    }
+
+    /// Current response gain for a stimulus (1 = fresh)
+    pub fn gain(&self, stimulus: u64) -> f32 {
+        self.gains.get(&stimulus).copied().unwrap_or(1.0)
+    }
+
+    /// Never encountered before
+    pub fn is_novel(&self, stimulus: u64) -> bool {
+        !self.gains.contains_key(&stimulus)
+    }
+
+    /// How strongly `d` is actually felt right now
+    pub fn felt(&self, d: &Disturbance) -> Disturbance {
+        let mut felt = d.clone();
+        if let Some(id) = d.stimulus {
+            felt.intensity *= self.gain(id);
+        }
+        felt
+    }
+
+    /// One exposure to `d` (call once per experience)
+    pub fn register(&mut self, d: &Disturbance) {
+        let Some(id) = d.stimulus else {
+            return;
+        };
+
+        if self.is_novel(id) {
+            for g in self.gains.values_mut() {
+                *g += (1.0 - *g) * self.dishabituation;
+            }
+        }
+
+        let g = self.gains.entry(id).or_insert(1.0);
+        *g = self.floor + (*g - self.floor) * (1.0 - self.rate);
+    }
+
+    /// One tick passes: habituated responses creep back
+    pub fn recover(&mut self) {
+        for g in self.gains.values_mut() {
+            *g += (1.0 - *g) * self.recovery;
+        }
+    }
+
+    /// Number of stimuli ever encountered
+    pub fn len(&self) -> usize {
+        self.gains.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.gains.is_empty()
+    }
+}
+
+impl Default for Habituation {
+    fn default() -> Self {
+        Self::new(0.2, 0.2, 0.01, 0.5)
+    }
+}
+
+/// Stable id for a feature vector (features quantized to 0.1)
+pub fn fingerprint(features: &[f32]) -> u64 {
+    // FNV-1a: deterministic across runs and platforms
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for f in features {
+        let q = (f * 10.0).round() as i32;
+        for byte in q.to_le_bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
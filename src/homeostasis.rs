@@ -0,0 +1,113 @@
+// homeostasis.rs
+//
+// Homeostasis (SELF-REGULATION)
+// -----------------------------
+// Runs exactly once per thinking tick, AFTER whichever
+// replay strategy moved the state.
+
+use std::fmt::Debug;
+
+use crate::genome::Genome;
+use crate::state::{StateVector, ENERGY, STABILITY, TENSION};
+
+pub trait Homeostasis: Debug {
+    fn regulate(&mut self, state: &mut StateVector);
+}
+
+/// Original newborn regulation
+///
+/// - low tension lets stability consolidate
+/// - energy is nudged back into a comfortable band
+#[derive(Debug, Clone)]
+pub struct BandHomeostasis {
+    pub calm_tension: f32,
+    pub stability_recovery: f32,
+    pub energy_band: (f32, f32),
+    pub energy_step: f32,
+}
+
+impl BandHomeostasis {
+    pub fn from_genome(g: &Genome) -> Self {
+        Self {
+            calm_tension: g.calm_tension,
+            stability_recovery: g.stability_recovery,
+            energy_band: g.energy_band,
+            energy_step: g.energy_balance_step,
+        }
+    }
+}
+
+impl Default for BandHomeostasis {
+    fn default() -> Self {
+        Self::from_genome(&Genome::default())
+    }
+}
+
+impl Homeostasis for BandHomeostasis {
+    fn regulate(&mut self, s: &mut StateVector) {
+        // Low tension allows regulation to consolidate
+        if s[TENSION] < self.calm_tension {
+            s[STABILITY] += self.stability_recovery;
+        }
+
+        // Energy balancing
+        if s[ENERGY] > self.energy_band.1 {
+            s[ENERGY] -= self.energy_step;
+        } else if s[ENERGY] < self.energy_band.0 {
+            s[ENERGY] += self.energy_step;
+        }
+    }
+}
+
+/// Set-point regulation
+///
+/// Energy relaxes toward its set-point with a time constant.
+/// A well-regulated brain (high stability) also releases
+/// tension toward its set-point: the calmer you are, the
+/// faster stress drains away.
+#[derive(Debug, Clone)]
+pub struct SetPointHomeostasis {
+    pub tension_set_point: f32,
+    pub energy_set_point: f32,
+
+    /// Ticks to close ~63% of the gap (larger = slower)
+    pub tension_tau: f32,
+    pub energy_tau: f32,
+
+    /// Stability needed before tension starts releasing
+    pub release_stability: f32,
+
+    pub calm_tension: f32,
+    pub stability_recovery: f32,
+}
+
+impl Default for SetPointHomeostasis {
+    fn default() -> Self {
+        Self {
+            tension_set_point: 0.3,
+            energy_set_point: 0.5,
+            tension_tau: 50.0,
+            energy_tau: 20.0,
+            release_stability: 0.5,
+            calm_tension: 0.4,
+            stability_recovery: 0.01,
+        }
+    }
+}
+
+impl Homeostasis for SetPointHomeostasis {
+    fn regulate(&mut self, s: &mut StateVector) {
+        // Stress release (only downwards, only when regulated)
+        if s[STABILITY] > self.release_stability && s[TENSION] > self.tension_set_point {
+            s[TENSION] += (self.tension_set_point - s[TENSION]) * s[STABILITY] / self.tension_tau;
+        }
+
+        // Low tension allows regulation to consolidate
+        if s[TENSION] < self.calm_tension {
+            s[STABILITY] += self.stability_recovery;
+        }
+
+        // Energy relaxes toward set-point
+        s[ENERGY] += (self.energy_set_point - s[ENERGY]) / self.energy_tau;
+    }
+}
@@ -0,0 +1,80 @@
+// hormone.rs
+//
+// Stress hormone (DELAYED, LINGERING)
+// -----------------------------------
+// Shock does not only jump tension instantly. It also triggers
+// secretion into a slow compartment:
+//
+//     shock -> secretion pool -> circulating level -> cleared
+//
+// The circulating level peaks a few thinking ticks AFTER the
+// shock, raises tension and suppresses stability gain while it
+// lingers, then clears away.
+
+use crate::plasticity::Plasticity;
+
+#[derive(Debug, Clone)]
+pub struct StressHormone {
+    /// Secreted but not yet circulating
+    pub pool: f32,
+
+    /// Circulating level (what the body feels)
+    pub level: f32,
+
+    /// Pool added per unit shock
+    pub secretion: f32,
+
+    /// Fraction of the pool released into circulation per tick
+    pub release: f32,
+
+    /// Fraction of the circulating level cleared per tick
+    pub clearance: f32,
+
+    /// Tension added per tick per unit level
+    pub tension_gain: f32,
+
+    /// Fraction of stability gain suppressed per unit level
+    pub stability_suppression: f32,
+}
+
+impl StressHormone {
+    /// Shock triggers secretion (effects come later)
+    pub fn drive(&mut self, shock: f32) {
+        self.pool += shock * self.secretion;
+    }
+
+    /// One tick of release + clearance kinetics
+    pub fn tick(&mut self) {
+        let released = self.pool * self.release;
+        self.pool -= released;
+        self.level += released;
+        self.level -= self.level * self.clearance;
+    }
+
+    /// Multiplier on stability gain while the hormone circulates
+    pub fn stability_factor(&self) -> f32 {
+        (1.0 - self.level * self.stability_suppression).max(0.0)
+    }
+
+    /// Reaction rates as bent by the circulating hormone
+    pub fn modulate(&self, p: Plasticity) -> Plasticity {
+        Plasticity {
+            stability_gain_rate: p.stability_gain_rate * self.stability_factor(),
+            ..p
+        }
+    }
+}
+
+impl Default for StressHormone {
+    fn default() -> Self {
+        Self {
+            pool: 0.0,
+            level: 0.0,
+            secretion: 1.0,
+            release: 0.2,
+            clearance: 0.1,
+            tension_gain: 0.02,
+            stability_suppression: 1.0,
+        }
+    }
+}
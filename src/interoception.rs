@@ -0,0 +1,194 @@
+// interoception.rs
+//
+// Interoception (THE BODY DISTURBS ITSELF)
+// ----------------------------------------
+// Not every disturbance comes from outside. Drives build up
+// inside the body while the brain is awake:
+//
+//     hunger, temperature discomfort, sleepiness, ...
+//
+// Once a drive crosses its threshold it emits an internal
+// `Disturbance` (an urge). The right external experience
+// (feeding for hunger) or sleep relieves it.
+
+use std::f32::consts::TAU;
+
+use crate::disturbance::{Disturbance, Modality};
+
+/// How a drive builds up per awake tick
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DriveGrowth {
+    /// Steady build-up
+    Accumulating { rate: f32 },
+    /// Eases toward a cycle that swings between 0 and 1 once per
+    /// period; `ease` is the fraction of the gap closed per tick
+    Periodic { period: f32, phase: f32, ease: f32 },
+}
+
+/// What relieves a drive
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Relief {
+    /// Non-aversive disturbances of this modality
+    Modality(Modality),
+    /// Ticks of sleep
+    Sleep,
+}
+
+#[derive(Debug, Clone)]
+pub struct Drive {
+    pub name: String,
+
+    /// Current urgency, 0..1
+    pub level: f32,
+
+    pub growth: DriveGrowth,
+
+    /// Above this the drive emits urges
+    pub threshold: f32,
+
+    /// Template of the emitted urge (intensity scaled by level)
+    pub urge: Disturbance,
+
+    /// Ticks between two urges while above threshold
+    pub refractory: u32,
+
+    pub relief: Relief,
+
+    /// Level removed per unit relief
+    pub satiation: f32,
+
+    quiet: u32,
+}
+
+impl Drive {
+    pub fn new(name: &str, growth: DriveGrowth, threshold: f32, urge: Disturbance, relief: Relief) -> Self {
+        Self {
+            name: name.to_string(),
+            level: 0.0,
+            growth,
+            threshold,
+            urge: urge.with_modality(Modality::Interoceptive),
+            refractory: 10,
+            relief,
+            satiation: 1.0,
+            quiet: 0,
+        }
+    }
+
+    /// Grows during the day, slowly (accumulating)
+    pub fn hunger() -> Self {
+        let urge = Disturbance::new(0.6, 0.5, 0.6).with_valence(-0.5);
+        Self::new("hunger", DriveGrowth::Accumulating { rate: 0.004 }, 0.7, urge, Relief::Modality(Modality::Taste))
+    }
+
+    /// Comes and goes with room temperature (periodic)
+    pub fn temperature_discomfort() -> Self {
+        let urge = Disturbance::new(0.4, 1.0, 0.3).with_valence(-0.3);
+        let growth = DriveGrowth::Periodic { period: 300.0, phase: 0.0, ease: 0.05 };
+        Self::new("temperature", growth, 0.8, urge, Relief::Modality(Modality::Temperature))
+    }
+
+    /// Builds while awake, relieved only by sleep
+    pub fn sleepiness() -> Self {
+        let urge = Disturbance::new(0.3, 1.0, 0.2).with_valence(-0.2);
+        let mut d = Self::new("sleepiness", DriveGrowth::Accumulating { rate: 0.002 }, 0.8, urge, Relief::Sleep);
+        d.satiation = 0.05;
+        d
+    }
+
+    /// One awake tick: grow, and maybe emit an urge
+    pub fn tick(&mut self, age: u64) -> Option<Disturbance> {
+        self.level += match self.growth {
+            DriveGrowth::Accumulating { rate } => rate,
+            DriveGrowth::Periodic { period, phase, ease } => {
+                let period = period.max(1.0);
+                let target = 0.5 + 0.5 * (TAU * (age as f32 + phase) / period).sin();
+                (target - self.level) * ease
+            }
+        };
+        self.level = self.level.clamp(0.0, 1.0);
+
+        self.quiet = self.quiet.saturating_sub(1);
+        if self.level < self.threshold || self.quiet > 0 {
+            return None;
+        }
+
+        self.quiet = self.refractory;
+        let mut urge = self.urge.clone();
+        urge.intensity *= self.level;
+        Some(urge)
+    }
+
+    /// Relieve by `amount` (clamped at 0)
+    pub fn relieve(&mut self, amount: f32) {
+        self.level = (self.level - amount * self.satiation).max(0.0);
+        if self.level < self.threshold {
+            self.quiet = 0;
+        }
+    }
+
+    pub fn is_urgent(&self) -> bool {
+        self.level >= self.threshold
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Interoception {
+    pub drives: Vec<Drive>,
+}
+
+impl Interoception {
+    /// Hunger, temperature discomfort and sleepiness
+    pub fn newborn() -> Self {
+        Self {
+            drives: vec![Drive::hunger(), Drive::temperature_discomfort(), Drive::sleepiness()],
+        }
+    }
+
+    pub fn with_drive(mut self, drive: Drive) -> Self {
+        self.drives.push(drive);
+        self
+    }
+
+    pub fn drive(&self, name: &str) -> Option<&Drive> {
+        self.drives.iter().find(|d| d.name == name)
+    }
+
+    pub fn drive_mut(&mut self, name: &str) -> Option<&mut Drive> {
+        self.drives.iter_mut().find(|d| d.name == name)
+    }
+
+    /// One awake tick; returns the urges that fired
+    pub fn tick(&mut self, age: u64) -> Vec<Disturbance> {
+        self.drives.iter_mut().filter_map(|d| d.tick(age)).collect()
+    }
+
+    /// An external experience may satisfy drives
+    pub fn satisfy(&mut self, d: &Disturbance) {
+        if d.valence < 0.0 {
+            return;
+        }
+        let Some(modality) = d.modality else {
+            return;
+        };
+        for drive in self.drives.iter_mut() {
+            if drive.relief == Relief::Modality(modality) {
+                drive.relieve(d.intensity);
+            }
+        }
+    }
+
+    /// One tick of sleep
+    pub fn sleep(&mut self) {
+        for drive in self.drives.iter_mut() {
+            if drive.relief == Relief::Sleep {
+                drive.relieve(1.0);
+            }
+        }
+    }
+
+    /// (name, level) of every drive
+    pub fn levels(&self) -> Vec<(String, f32)> {
+        self.drives.iter().map(|d| (d.name.clone(), d.level)).collect()
+    }
+}
@@ -13,9 +13,26 @@
 //
 // Step 7: Structural Memory (Phase 1)
 
+use crate::allostasis::Allostasis;
 use crate::disturbance::Disturbance;
-use crate::bias::BiasField;
-use crate::memory::ExperienceTrace;
+use crate::dynamics::{combined_rates, Integrator, Overlap, TimeStep};
+use crate::forgetting::{fade, Forgetting};
+use crate::behavior::{Action, ActionCosts, BehaviorInput, Policy, ThresholdPolicy};
+use crate::bias::{dominant, BiasField, BiasRecall, BiasSemantics, Splitting};
+use crate::circadian::Circadian;
+use crate::clustering::Clustering;
+use crate::competition::BiasCompetition;
+use crate::development::{Development, Learning};
+use crate::genome::{limit, Genome};
+use crate::habituation::Habituation;
+use crate::homeostasis::{BandHomeostasis, Homeostasis};
+use crate::hormone::StressHormone;
+use crate::interoception::Interoception;
+use crate::memory::{EpisodicBuffer, EvictionPolicy, ExperienceTrace};
+use crate::metric::{Metric, L1};
+use crate::noise::MindWandering;
+use crate::plasticity::{default_rules, Plasticity, PlasticityInput, PlasticityRule};
+use crate::state::{Snapshot, StateVector, StepReport, ENERGY, STABILITY, TENSION};
 
 #[derive(Debug, Clone, Copy)]
 pub enum ReplayMode {
@@ -30,14 +47,10 @@ pub struct InternalStateField {
     // CORE INTERNAL PHYSIOLOGY (SELF STATE)
     // --------------------------------------------------
 
-    /// Nervous arousal / stress tone
-    pub tension: f32,
-
-    /// Regulation capacity (slow changing)
-    pub stability: f32,
-
-    /// Metabolic / activation energy
-    pub energy: f32,
+    /// One value per genome dimension:
+    /// tension (stress tone), stability (regulation capacity),
+    /// energy (metabolic activation), then any extra dimensions
+    pub state: StateVector,
 
     // --------------------------------------------------
     // PLASTICITY (HOW THE BRAIN REACTS)
@@ -53,15 +66,95 @@ pub struct InternalStateField {
     /// How energy reacts to stimulation
     pub energy_gain_rate: f32,
 
+    /// Rules that retune the rates above after each experience
+    pub plasticity_rules: Vec<Box<dyn PlasticityRule>>,
+
+    // --------------------------------------------------
+    // DEVELOPMENT (AGING)
+    // --------------------------------------------------
+
+    /// Ticks of simulated time lived so far. One lived step is one tick,
+    /// counted once however much happens in it: a thinking tick, a
+    /// standalone `apply_disturbance`, a `Runner` or `Timeline` tick,
+    /// a tick of sleep, or a whole time unit of a continuous disturbance
+    pub age: u64,
+
+    /// How learning slows with age, and its critical periods
+    pub development: Development,
+
+    /// Slow mood integrating chronic strain (None = no chronic effects)
+    pub allostasis: Option<Allostasis>,
+
+    /// Delayed stress-hormone response to shock (None = instant-jump model)
+    pub hormone: Option<StressHormone>,
+
+    /// Day/night clock + fatigue (None = timeless)
+    pub circadian: Option<Circadian>,
+
+    /// Bodily drives that emit internal disturbances (empty = none)
+    pub interoception: Interoception,
+
+    // --------------------------------------------------
+    // BEHAVIOURAL OUTPUT
+    // --------------------------------------------------
+
+    /// Maps state + dominant basin to a behaviour
+    pub policy: Box<dyn Policy>,
+
+    /// What acting costs the body
+    pub action_costs: ActionCosts,
+
+    /// Most recent behaviour (None = never acted)
+    pub last_action: Option<Action>,
+
     // --------------------------------------------------
     // STRUCTURAL MEMORY (STEP 7 CORE)
     // --------------------------------------------------
 
+    /// Bounded episodic buffer (raw experiences, used by trace replay)
+    pub memory: EpisodicBuffer,
+
     /// Directional bias field
     /// This replaces explicit episodic memory over time
-    pub memory: Vec<ExperienceTrace>,
     pub biases: Vec<BiasField>,
     pub replay_mode: ReplayMode,
+
+    /// Which bias (or mix) structural replay follows
+    pub bias_recall: BiasRecall,
+
+    /// Push along directions, or pull toward basin targets
+    pub bias_semantics: BiasSemantics,
+
+    /// How biases compete in reinforcement and replay
+    pub competition: BiasCompetition,
+
+    /// How basins fade and when dead ones are pruned
+    pub forgetting: Forgetting,
+
+    /// Per-stimulus response gains (repetition dampens, novelty restores)
+    pub habituation: Habituation,
+
+    /// Geometry of bias space (matching + clustering)
+    pub metric: Box<dyn Metric>,
+
+    /// When and how similar basins merge
+    pub clustering: Clustering,
+
+    /// When blurred basins split (None = never)
+    pub splitting: Option<Splitting>,
+
+    /// Self-regulation, applied once per thinking tick
+    pub homeostasis: Box<dyn Homeostasis>,
+
+    /// Spontaneous drift during thinking (None = deterministic silence)
+    pub noise: Option<MindWandering>,
+
+    // --------------------------------------------------
+    // GENETIC PRIOR (FIXED FOR LIFE)
+    // --------------------------------------------------
+
+    /// Physiology constants this brain was born with
+    pub genome: Genome,
 }
 
 impl InternalStateField {
@@ -69,158 +162,633 @@ impl InternalStateField {
     // BIRTH INITIALIZATION (GENETIC PRIOR)
     // --------------------------------------------------
     pub fn new() -> Self {
+        Self::with_config(Genome::default())
+    }
+
+    /// Newborn grown from a custom genome (temperament)
+    pub fn with_config(genome: Genome) -> Self {
         Self {
             // Newborn internal state
-            tension: 0.5,    // neutral
-            stability: 0.1,  // very low regulation
-            energy: 0.5,     // baseline vitality
+            state: genome.birth_state(),
 
             // Newborn plasticity (highly sensitive)
-            shock_sensitivity: 0.2,
-            stability_gain_rate: 0.05,
-            energy_gain_rate: 0.1,
-            
+            shock_sensitivity: genome.shock_sensitivity,
+            stability_gain_rate: genome.stability_gain_rate,
+            energy_gain_rate: genome.energy_gain_rate,
+            plasticity_rules: default_rules(&genome),
+
+            // Just born
+            age: 0,
+            development: Development::default(),
+            allostasis: None,
+            hormone: None,
+            circadian: None,
+            interoception: Interoception::default(),
+            policy: Box::new(ThresholdPolicy::default()),
+            action_costs: ActionCosts::default(),
+            last_action: None,
 
             replay_mode: ReplayMode::StructuralBias, // default testing mode
+            bias_recall: BiasRecall::Strongest,
+            bias_semantics: BiasSemantics::Direction,
+            competition: BiasCompetition::default(),
+            forgetting: Forgetting::per_disturbance(genome.bias_decay),
+            habituation: Habituation::default(),
+            metric: Box::new(L1),
+            clustering: Clustering::new(genome.bias_merge_distance, genome.cluster_trigger),
+            splitting: None,
+            homeostasis: Box::new(BandHomeostasis::from_genome(&genome)),
+            noise: None,
             // No memory at birth
-            memory: Vec::new(),
+            memory: EpisodicBuffer::default(),
             biases: Vec::new(),
+
+            genome,
+        }
+    }
+
+    /// Newborn with a custom episodic buffer
+    pub fn with_memory(capacity: usize, policy: EvictionPolicy) -> Self {
+        Self {
+            memory: EpisodicBuffer::new(capacity, policy),
+            ..Self::new()
         }
     }
 
     // --------------------------------------------------
     // EXTERNAL EXPERIENCE APPLICATION
     // --------------------------------------------------
-    pub fn apply_disturbance(&mut self, d: &Disturbance) {
+
+    /// One instant experience, lived as one tick
+    pub fn apply_disturbance(&mut self, stimulus: &Disturbance) -> StepReport {
+        let report = self.feel(stimulus);
+        self.elapse(false);
+        report
+    }
+
+    /// Instant physiology + learning, without time passing
+    /// (several experiences can share one tick)
+    pub(crate) fn feel(&mut self, stimulus: &Disturbance) -> StepReport {
         // Capture "before" state
         // (used ONLY to compute direction of change)
-        let before_tension = self.tension;
-        let before_stability = self.stability;
-        let before_energy = self.energy;
+        let before = self.state.clone();
+
+        // A familiar stimulus is felt weaker
+        let felt = self.habituation.felt(stimulus);
+        let d = &felt;
 
         // ----------------------------------------------
         // RAW PHYSICAL INTERPRETATION OF INPUT
         // ----------------------------------------------
 
         // Shock = strong + sudden
-        let shock = d.intensity * d.suddenness;
+        let shock = d.shock();
 
         // Calm = long + predictable
-        let calm = d.duration * (1.0 - d.suddenness);
+        let calm = d.calm();
 
         // ----------------------------------------------
         // PHYSIOLOGICAL RESPONSE (NOT DECISION)
         // ----------------------------------------------
 
+        let p = self.effective_plasticity();
+
         // Shock increases tension
-        self.tension += shock * self.shock_sensitivity;
+        self.state[TENSION] += shock * p.shock_sensitivity;
 
         // Calm increases regulation capacity
-        self.stability += calm * self.stability_gain_rate;
+        self.state[STABILITY] += calm * p.stability_gain_rate;
 
         // Energy dynamics (dual nature)
-        self.energy += d.intensity * self.energy_gain_rate;
-        self.energy -= shock * (self.energy_gain_rate * 0.5);
+        self.state[ENERGY] += d.intensity * p.energy_gain_rate;
+        self.state[ENERGY] -= shock * (p.energy_gain_rate * self.genome.shock_energy_drain);
 
-        // ----------------------------------------------
-        // STRUCTURAL MEMORY REINFORCEMENT (STEP 7 CORE)
-        // ----------------------------------------------
-        //
-        // We DO NOT store the experience.
-        // We only reinforce the DIRECTION in which
-        // the internal state moved.
-        //
-        // This is equivalent to synaptic strengthening.
-        // dt is daltas (differences) in tension, stability, energy
-        let dt = self.tension - before_tension;
-        let ds = self.stability - before_stability;
-        let de = self.energy - before_energy;
+        // Valence: comfort soothes + restores, harm stresses
+        self.state[TENSION] -= d.comfort() * self.genome.comfort_soothing;
+        self.state[ENERGY] += d.comfort() * self.genome.comfort_restoration;
+        self.state[TENSION] += d.harm() * self.genome.harm_stress;
 
-        // --- Bias competition ---
-        let mut best_idx = None;
-        let mut best_score = f32::MAX;
+        self.learn_from_experience(before, stimulus)
+    }
 
-        for (i, b) in self.biases.iter().enumerate() {
-            let score = b.similarity(dt, ds, de);
-            if score < best_score {
-                best_score = score;
-                best_idx = Some(i);
-            }
+    // --------------------------------------------------
+    // CONTINUOUS-TIME EXPERIENCE (TIME-STEPPED)
+    // --------------------------------------------------
+
+    /// Integrate the physiology of `d` for `h` time units (no learning)
+    pub fn integrate(&mut self, d: &Disturbance, h: f32, integrator: Integrator) {
+        self.integrate_combined(&[(d, 1.0)], Overlap::Sum, h, integrator);
+    }
+
+    /// Integrate several overlapping disturbances for `h` time units
+    pub fn integrate_combined(
+        &mut self,
+        active: &[(&Disturbance, f32)],
+        overlap: Overlap,
+        h: f32,
+        integrator: Integrator,
+    ) {
+        let felt: Vec<Disturbance> = active.iter().map(|(d, _)| self.habituation.felt(d)).collect();
+        let active: Vec<(&Disturbance, f32)> = felt.iter().zip(active.iter()).map(|(f, (_, w))| (f, *w)).collect();
+
+        let p = self.effective_plasticity();
+        let g = &self.genome;
+        self.state = integrator.step(&self.state, h, |s| combined_rates(s, &active, overlap, &p, g));
+
+        self.clamp_state();
+    }
+
+    /// Let `d` unfold over its full duration, then learn from it
+    pub fn apply_disturbance_over_time(&mut self, d: &Disturbance, step: TimeStep) -> StepReport {
+        self.apply_disturbance_interleaved(d, step, |_| {})
+    }
+
+    /// Like `apply_disturbance_over_time`, but `between` runs after
+    /// every step (e.g. sampling the state while being rocked).
+    /// The event is lived as its duration in whole ticks (at least one)
+    pub fn apply_disturbance_interleaved(
+        &mut self,
+        d: &Disturbance,
+        step: TimeStep,
+        mut between: impl FnMut(&mut Self),
+    ) -> StepReport {
+        // Zero-length events are plain jumps
+        if d.duration <= 0.0 {
+            return self.apply_disturbance(d);
         }
 
-        // Threshold: kitna similar hona chahiye
-        if let Some(i) = best_idx {
-            if best_score < 0.2 {
-                self.biases[i].reinforce(dt, ds, de);
-            } else {
-                self.biases.push(BiasField::new());
-                self.biases.last_mut().unwrap().reinforce(dt, ds, de);
+        let before = self.state.clone();
+
+        let steps = step.steps(d.duration);
+        let h = d.duration / steps as f32;
+        let ticks = (d.duration.round() as usize).max(1);
+
+        for k in 1..=steps {
+            self.integrate(d, h, step.integrator);
+            between(self);
+
+            // Ticks are spread evenly over the steps
+            for _ in (k - 1) * ticks / steps..k * ticks / steps {
+                self.elapse(false);
             }
-        } else {
-            let mut b = BiasField::new();
-            b.reinforce(dt, ds, de);
-            self.biases.push(b);
         }
 
-        // Decay all non-dominant biases
-        for b in self.biases.iter_mut() {
-            b.decay();
+        self.learn_from_experience(before, d)
+    }
+
+    /// Current reaction rates as one value
+    pub fn plasticity(&self) -> Plasticity {
+        Plasticity {
+            shock_sensitivity: self.shock_sensitivity,
+            stability_gain_rate: self.stability_gain_rate,
+            energy_gain_rate: self.energy_gain_rate,
+        }
+    }
+
+    /// Rates the body actually reacts with (chronic load bends them)
+    pub fn effective_plasticity(&self) -> Plasticity {
+        let mut p = self.plasticity();
+        if let Some(a) = &self.allostasis {
+            p = a.modulate(p);
+        }
+        if let Some(h) = &self.hormone {
+            p = h.modulate(p);
+        }
+        if let Some(c) = &self.circadian {
+            p = c.modulate(p);
+        }
+        p
+    }
+
+    /// Everything that happens AFTER the body reacted:
+    /// bias reinforcement, plasticity, limits, episodic capture
+    pub(crate) fn learn_from_experience(&mut self, before: StateVector, d: &Disturbance) -> StepReport {
+        let mut report = StepReport::default();
+        let felt = self.habituation.felt(d);
+        let shock = felt.shock();
+        let calm = felt.calm();
+
+        // This exposure counts toward habituation
+        self.habituation.register(d);
+
+        // Shock starts a slow hormone response
+        if let Some(h) = self.hormone.as_mut() {
+            h.drive(shock);
+        }
+
+        // Every experience tires the body
+        if let Some(c) = self.circadian.as_mut() {
+            c.exert(felt.intensity);
         }
 
+        // Feeding, warming, ... satisfy drives
+        self.interoception.satisfy(d);
+
         // ----------------------------------------------
-        // PLASTICITY ADAPTATION (LEARNING HOW TO REACT)
+        // STRUCTURAL MEMORY REINFORCEMENT (STEP 7 CORE)
         // ----------------------------------------------
+        let after = self.state.clone();
+        let reinforced = self.reinforce_biases(&before, &after, d.valence, &mut report);
 
-        // Repeated shock with good regulation → desensitization
-        if shock > 0.3 && self.stability > 0.6 {
-            self.shock_sensitivity *= 0.98;
+        // Only the basin just reinforced moved; the full pass waits for sleep.
+        // Runs before pruning, which would shift `reinforced`.
+        if self.biases.len() > self.clustering.trigger {
+            report.merged += self.clustering.merge_into(&mut self.biases, reinforced, self.metric.as_ref());
         }
 
-        // Long calm exposure → faster regulation learning
-        if calm > 1.0 {
-            self.stability_gain_rate *= 1.02;
+        // Decay all non-dominant biases
+        if let Some(curve) = self.forgetting.on_disturbance {
+            fade(curve, &mut self.biases);
         }
+        report.pruned += self.forgetting.prune(&mut self.biases);
 
-        // Chronic overload → energy efficiency adjustment
-        if self.tension > 1.0 {
-            self.energy_gain_rate *= 0.99;
+        // ----------------------------------------------
+        // PLASTICITY ADAPTATION (LEARNING HOW TO REACT)
+        // ----------------------------------------------
+
+        let input = PlasticityInput {
+            shock,
+            calm,
+            tension: self.state[TENSION],
+            stability: self.state[STABILITY],
+            energy: self.state[ENERGY],
+        };
+
+        let mut p = self.plasticity();
+
+        for rule in self.plasticity_rules.iter_mut() {
+            let old = p;
+            rule.adapt(&input, &mut p);
+
+            // Age scales how far each rule may move the rates
+            let m = self
+                .development
+                .multiplier(&Learning::Rule(rule.name().to_string()), self.age);
+            if m != 1.0 {
+                p.shock_sensitivity = old.shock_sensitivity + (p.shock_sensitivity - old.shock_sensitivity) * m;
+                p.stability_gain_rate = old.stability_gain_rate + (p.stability_gain_rate - old.stability_gain_rate) * m;
+                p.energy_gain_rate = old.energy_gain_rate + (p.energy_gain_rate - old.energy_gain_rate) * m;
+            }
         }
 
+        self.shock_sensitivity = p.shock_sensitivity;
+        self.stability_gain_rate = p.stability_gain_rate;
+        self.energy_gain_rate = p.energy_gain_rate;
+
         // ----------------------------------------------
         // BIOLOGICAL LIMITS (NOT RULES)
         // ----------------------------------------------
 
-        self.tension = self.tension.clamp(0.0, 1.5);
-        self.stability = self.stability.clamp(0.0, 1.0);
-        self.energy = self.energy.clamp(0.0, 1.0);
+        self.clamp_state();
+
+        let g = &self.genome;
+        // Only a rule that allows it may push past the normal range
+        let (lo, mut hi) = g.shock_sensitivity_range;
+        if self.plasticity_rules.iter().any(|r| r.allows_hypervigilance()) {
+            hi = hi.max(g.hypervigilance_limit);
+        }
+        self.shock_sensitivity = limit(self.shock_sensitivity, (lo, hi));
+        self.stability_gain_rate = limit(self.stability_gain_rate, g.stability_gain_rate_range);
+        self.energy_gain_rate = limit(self.energy_gain_rate, g.energy_gain_rate_range);
+
+        // ----------------------------------------------
+        // EPISODIC CAPTURE (BOUNDED)
+        // ----------------------------------------------
+
+        self.memory.record(ExperienceTrace {
+            before,
+            disturbance: d.clone(),
+            after: self.state.clone(),
+            replays: 0,
+        });
+
+        if let Some(a) = self.allostasis.as_mut() {
+            a.update(&self.state);
+        }
+
+        report
+    }
+
+    /// Carve one state change into the bias field:
+    /// competition, reinforcement, and splitting of blurred basins.
+    /// Returns the index of the basin that absorbed the change.
+    fn reinforce_biases(
+        &mut self,
+        before: &StateVector,
+        after: &StateVector,
+        valence: f32,
+        report: &mut StepReport,
+    ) -> usize {
+        // We DO NOT store the experience.
+        // We only reinforce the DIRECTION in which
+        // the internal state moved.
+        //
+        // This is equivalent to synaptic strengthening.
+        // delta is the difference in every state dimension
+        let delta = after.delta(before);
+
+        // --- Bias competition ---
+        // Threshold: kitna similar hona chahiye
+        // Only basins under the threshold compete; fitness = margin under it
+        let threshold = self.genome.bias_match_threshold;
+        let mut fitness: Vec<f32> = self
+            .biases
+            .iter()
+            .map(|b| threshold - b.similarity_with(&delta, self.metric.as_ref()))
+            .map(|f| if f > 0.0 { f } else { f32::NEG_INFINITY })
+            .collect();
+
+        let refs: Vec<&BiasField> = self.biases.iter().collect();
+        self.competition.inhibit(&mut fitness, &refs, self.metric.as_ref());
+        let winner = self.competition.select(&fitness);
+
+        // Younger brains carve deeper
+        let gain = self.development.multiplier(&Learning::Reinforcement, self.age);
+        let rate = self.genome.bias_learning_rate * gain;
+        let step = self.genome.bias_strength_step * gain;
+
+        let i = match winner {
+            Some(i) => i,
+            None => {
+                self.biases.push(BiasField::new(delta.len()));
+                self.biases.len() - 1
+            }
+        };
 
-        self.shock_sensitivity = self.shock_sensitivity.clamp(0.05, 0.5);
-        self.stability_gain_rate = self.stability_gain_rate.clamp(0.01, 0.2);
-        self.energy_gain_rate = self.energy_gain_rate.clamp(0.05, 0.3);
+        let b = &mut self.biases[i];
+        b.reinforce_with(&delta, rate, step);
+        b.remember_context(before);
+        b.remember_target(after);
+        b.remember_valence(valence);
 
-        if self.biases.len() > 5 {
-            self.cluster_biases();
+        // A basin absorbing too many different experiences splits in two
+        if let Some(s) = self.splitting
+            && self.biases[i].should_split(&s)
+        {
+            let (left, right) = self.biases[i].split();
+            self.biases[i] = left;
+            self.biases.push(right);
+            report.split += 1;
         }
+
+        i
+    }
+
+    /// Remove a plasticity rule by name (ablation experiments)
+    pub fn ablate_rule(&mut self, name: &str) {
+        self.plasticity_rules.retain(|r| r.name() != name);
     }
 
     // --------------------------------------------------
     // INTERNAL THINKING LOOP (NO INPUT)
     // --------------------------------------------------
 
-    pub fn internal_thinking_tick(&mut self) {
+    pub fn internal_thinking_tick(&mut self) -> StepReport {
+        let mut report = StepReport::default();
+
         match self.replay_mode {
             ReplayMode::TraceBased => self.trace_thinking_tick(),
             ReplayMode::StructuralBias => self.bias_thinking_tick(),
         }
 
-        // common homeostasis
-        if self.tension < 0.4 {
-            self.stability += 0.01;
+        // --------------------------------------------------
+        // INTERNAL DRIFT (PREVENTS FREEZING)
+        // --------------------------------------------------
+
+        if let Some(noise) = self.noise.as_mut() {
+            noise.perturb(&mut self.state);
+        }
+
+        // --------------------------------------------------
+        // HOMEOSTASIS (SELF-REGULATION, ONCE PER TICK)
+        // --------------------------------------------------
+
+        let stability_before = self.state[STABILITY];
+        self.homeostasis.regulate(&mut self.state);
+
+        // Circulating stress hormone: tension up, stability gain held back
+        if let Some(h) = &self.hormone {
+            self.state[TENSION] += h.level * h.tension_gain;
+
+            let gained = self.state[STABILITY] - stability_before;
+            if gained > 0.0 {
+                self.state[STABILITY] = stability_before + gained * h.stability_factor();
+            }
+        }
+
+        // Resting energy follows the time of day
+        if let Some(c) = &self.circadian {
+            self.state[ENERGY] += c.energy_drift(self.state[ENERGY]);
+        }
+
+        // Chronic load keeps tension from fully settling
+        if let Some(a) = self.allostasis.as_mut() {
+            self.state[TENSION] += a.tension_drift(self.state[TENSION]);
+            a.update(&self.state);
+        }
+
+        self.clamp_state();
+
+        // --------------------------------------------------
+        // FORGETTING (IDLE TIME FADES UNUSED BASINS)
+        // --------------------------------------------------
+
+        if let Some(curve) = self.forgetting.on_tick {
+            fade(curve, &mut self.biases);
+        }
+        report.pruned += self.forgetting.prune(&mut self.biases);
+
+        // --------------------------------------------------
+        // INTEROCEPTION (THE BODY DISTURBS ITSELF)
+        // --------------------------------------------------
+
+        // Urges are felt within this same tick
+        for urge in self.interoception.tick(self.age) {
+            report.absorb(self.feel(&urge));
+            report.urges += 1;
+        }
+
+        self.elapse(false);
+
+        report
+    }
+
+    // --------------------------------------------------
+    // SLEEP (OFFLINE CONSOLIDATION)
+    // --------------------------------------------------
+
+    /// Sleep for `duration` ticks: episodic traces are replayed into
+    /// biases, basins merge and prune, energy is restored, tension
+    /// released, and the episodic buffer is emptied.
+    pub fn sleep(&mut self, duration: u32) -> StepReport {
+        let mut report = StepReport::default();
+
+        // Replay the day into structural memory
+        let traces: Vec<ExperienceTrace> = self.memory.iter().cloned().collect();
+        for t in &traces {
+            self.reinforce_biases(&t.before, &t.after, t.disturbance.valence, &mut report);
+        }
+        self.memory.clear();
+
+        // Consolidate: similar basins merge, dead ones go
+        report.merged += self.clustering.cluster(&mut self.biases, self.metric.as_ref());
+        report.pruned += self.forgetting.prune(&mut self.biases);
+
+        // Rest
+        for _ in 0..duration {
+            self.state[ENERGY] += self.genome.sleep_energy_restore;
+            self.state[TENSION] -= self.state[TENSION] * self.genome.sleep_tension_release;
+
+            if let Some(a) = self.allostasis.as_mut() {
+                a.update(&self.state);
+            }
+            self.interoception.sleep();
+
+            self.clamp_state();
+            self.elapse(true);
+        }
+
+        report
+    }
+
+    /// One tick of simulated time passes (one lived step, see `age`):
+    /// the day/night clock moves, hormone kinetics run, habituated
+    /// responses creep back and fatigue drains (faster asleep)
+    pub(crate) fn elapse(&mut self, asleep: bool) {
+        if let Some(h) = self.hormone.as_mut() {
+            h.tick();
+        }
+        if let Some(c) = self.circadian.as_mut() {
+            if asleep {
+                c.sleep();
+            } else {
+                c.rest();
+            }
+            c.advance();
+        }
+        self.habituation.recover();
+        self.age += 1;
+    }
+
+    // --------------------------------------------------
+    // BEHAVIOURAL OUTPUT
+    // --------------------------------------------------
+
+    /// Strongest basin, if any
+    pub fn dominant_bias(&self) -> Option<&BiasField> {
+        dominant(&self.biases)
+    }
+
+    /// Emit one behaviour from the current state and pay for it
+    pub fn act(&mut self) -> Action {
+        let urgency = self
+            .interoception
+            .drives
+            .iter()
+            .filter(|d| d.is_urgent())
+            .map(|d| d.level)
+            .fold(0.0, f32::max);
+
+        // Field borrow, so the policy can be borrowed mutably below
+        let input = BehaviorInput {
+            state: &self.state,
+            dominant: dominant(&self.biases),
+            sleep_pressure: self.sleep_pressure(),
+            urgency,
+        };
+        let action = self.policy.act(&input);
+
+        self.action_costs.pay(&action, &mut self.state);
+        self.clamp_state();
+
+        self.last_action = Some(action);
+        action
+    }
+
+    /// How strongly the body wants to sleep (0 when timeless)
+    pub fn sleep_pressure(&self) -> f32 {
+        self.circadian.as_ref().map_or(0.0, |c| c.sleep_pressure())
+    }
+
+    /// Circulating stress hormone (0 when not modelled)
+    pub fn hormone_level(&self) -> f32 {
+        self.hormone.as_ref().map_or(0.0, |h| h.level)
+    }
+
+    /// Slow mood: 0 rested .. 1 worn out (0 when not modelled)
+    pub fn allostatic_load(&self) -> f32 {
+        self.allostasis.as_ref().map_or(0.0, |a| a.load)
+    }
+
+    /// Sensitized past the normal range of shock sensitivity
+    pub fn is_hypervigilant(&self) -> bool {
+        self.shock_sensitivity > self.genome.shock_sensitivity_range.1
+    }
+
+    /// Observable state at `tick`
+    pub fn snapshot(&self, tick: u32) -> Snapshot {
+        Snapshot {
+            tick,
+            state: self.state.clone(),
+            shock_sensitivity: self.shock_sensitivity,
+            stability_gain_rate: self.stability_gain_rate,
+            energy_gain_rate: self.energy_gain_rate,
+            age: self.age,
+            allostatic_load: self.allostatic_load(),
+            hormone: self.hormone_level(),
+            fatigue: self.circadian.as_ref().map_or(0.0, |c| c.fatigue),
+            sleep_pressure: self.sleep_pressure(),
+            drives: self.interoception.levels(),
+            bias_count: self.biases.len(),
+            active_disturbances: 0,
+            pruned: 0,
+            split: 0,
+        }
+    }
+
+    /// Nervous arousal / stress tone
+    pub fn tension(&self) -> f32 {
+        self.state[TENSION]
+    }
+
+    /// Regulation capacity (slow changing)
+    pub fn stability(&self) -> f32 {
+        self.state[STABILITY]
+    }
+
+    /// Metabolic / activation energy
+    pub fn energy(&self) -> f32 {
+        self.state[ENERGY]
+    }
+
+    /// Value of a named dimension
+    pub fn value(&self, name: &str) -> Option<f32> {
+        self.genome.dimension_index(name).map(|i| self.state[i])
+    }
+
+    /// Set a named dimension (within its limits); false if unknown
+    pub fn set_value(&mut self, name: &str, value: f32) -> bool {
+        match self.genome.dimension_index(name) {
+            Some(i) => {
+                self.state[i] = limit(value, self.genome.dimensions[i].range);
+                true
+            }
+            None => false,
         }
+    }
 
-        self.tension = self.tension.clamp(0.0, 1.5);
-        self.stability = self.stability.clamp(0.0, 1.0);
-        self.energy = self.energy.clamp(0.0, 1.0);
+    /// Regulation from outside (someone holding the newborn);
+    /// biological limits still apply afterwards
+    pub fn co_regulate(&mut self, regulate: impl FnOnce(&mut StateVector)) {
+        regulate(&mut self.state);
+        self.clamp_state();
+    }
+
+    /// Biological limits on every state dimension
+    fn clamp_state(&mut self) {
+        for (v, dim) in self.state.iter_mut().zip(self.genome.dimensions.iter()) {
+            *v = limit(*v, dim.range);
+        }
     }
 
 
@@ -237,51 +805,71 @@ impl InternalStateField {
         // - subconscious processing
         // - imagination precursor
         // - reasoning substrate
+        //
+        // Which direction depends on `bias_recall`:
+        // a bias learned while calm should not drive a shocked brain.
+
+        let gain = self.genome.bias_replay_gain;
+        let semantics = self.bias_semantics;
+        let state = &self.state;
+
+        let mut fitness: Vec<f32> = match self.bias_recall {
+            BiasRecall::Strongest => self.biases.iter().map(|b| b.strength).collect(),
+            BiasRecall::Contextual { width } | BiasRecall::Blend { width } => self
+                .biases
+                .iter()
+                .map(|b| b.strength * b.proximity_with(state, width, self.metric.as_ref()))
+                .collect(),
+        };
+
+        let refs: Vec<&BiasField> = self.biases.iter().collect();
+        self.competition.inhibit(&mut fitness, &refs, self.metric.as_ref());
+
+        let (replayed, push) = match self.bias_recall {
+            BiasRecall::Strongest | BiasRecall::Contextual { .. } => {
+                let winner = self.competition.select(&fitness);
+                (winner, winner.map(|i| self.biases[i].replay_push(state, semantics, gain)))
+            }
+            BiasRecall::Blend { .. } => {
+                let total: f32 = fitness.iter().filter(|w| w.is_finite()).sum();
+
+                // Main contributor counts as used
+                let main = BiasCompetition::default().select(&fitness);
+
+                let mix = (total > 0.0).then(|| {
+                    let mut mix = vec![0.0; state.len()];
+                    for (b, w) in self.biases.iter().zip(fitness) {
+                        if !w.is_finite() {
+                            continue;
+                        }
+                        for (m, p) in mix.iter_mut().zip(b.replay_push(state, semantics, gain)) {
+                            *m += p * (w / total);
+                        }
+                    }
+                    mix
+                });
+                (main, mix)
+            }
+        };
 
-        if let Some(best) = self.biases.iter().max_by(|a, b| a.strength.partial_cmp(&b.strength).unwrap())
-        {
-            self.tension += best.dt_pref * best.strength * 0.05;
-            self.stability += best.ds_pref * best.strength * 0.05;
-            self.energy += best.de_pref * best.strength * 0.05;
-        }
-
-        // --------------------------------------------------
-        // HOMEOSTASIS (SELF-REGULATION)
-        // --------------------------------------------------
-
-        // Low tension allows regulation to consolidate
-        if self.tension < 0.4 {
-            self.stability += 0.01;
+        if let Some(push) = push {
+            for (v, p) in self.state.iter_mut().zip(push) {
+                *v += p;
+            }
         }
 
-        // Energy balancing
-        if self.energy > 0.6 {
-            self.energy -= 0.01;
-        } else if self.energy < 0.4 {
-            self.energy += 0.01;
+        if let Some(i) = replayed {
+            self.biases[i].replays += 1;
         }
 
         // --------------------------------------------------
         // BIOLOGICAL LIMITS
         // --------------------------------------------------
 
-        self.tension = self.tension.clamp(0.0, 1.5);
-        self.stability = self.stability.clamp(0.0, 1.0);
-        self.energy = self.energy.clamp(0.0, 1.0);
+        self.clamp_state();
     }
 
     pub fn trace_thinking_tick(&mut self) {
-    
-        if self.tension < 0.4 {
-            self.stability += 0.01;
-        }
-
-        // Energy balancing
-        if self.energy > 0.6 {
-            self.energy -= 0.01;
-        } else if self.energy < 0.4 {
-            self.energy += 0.01;
-        }
         if self.memory.is_empty() {
             return;
         }
@@ -289,11 +877,8 @@ impl InternalStateField {
         let mut best = None;
         let mut best_score = f32::MAX;
 
-        for t in &self.memory {
-            let score =
-                (self.tension - t.tension_before).abs() +
-                (self.stability - t.stability_before).abs() +
-                (self.energy - t.energy_before).abs();
+        for t in self.memory.iter_mut() {
+            let score = self.state.l1(&t.before);
 
             if score < best_score {
                 best_score = score;
@@ -302,35 +887,23 @@ impl InternalStateField {
         }
 
         if let Some(t) = best {
-            let dt = t.tension_after - t.tension_before;
-            let ds = t.stability_after - t.stability_before;
-            let de = t.energy_after - t.energy_before;
+            let delta = t.delta();
 
-            self.tension += dt * 0.2;
-            self.stability += ds * 0.2;
-            self.energy += de * 0.2;
-        }
-    }
-
-    fn cluster_biases(&mut self) {
-        let mut i = 0;
-
-        while i < self.biases.len() {
-            let mut j = i + 1;
-            while j < self.biases.len() {
-                let dist = self.biases[i].distance(&self.biases[j]);
+            // Rumination fades: the same episode replays ever fainter,
+            // so total drift stays bounded like bias replay
+            let gain = self.genome.trace_replay_gain * self.genome.trace_replay_fade.powi(t.replays as i32);
+            t.replays = t.replays.saturating_add(1);
 
-                // Threshold: same basin
-                if dist < 0.1 {
-                    let other = self.biases[j].clone();
-                    self.biases[i].merge(&other);
-                    self.biases.remove(j);
-                } else {
-                    j += 1;
-                }
+            for (v, d) in self.state.iter_mut().zip(delta.iter()) {
+                *v += d * gain;
             }
-            i += 1;
         }
     }
 
 }
+
+impl Default for InternalStateField {
+    fn default() -> Self {
+        Self::new()
+    }
+}
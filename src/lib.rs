@@ -1,4 +1,25 @@
+pub mod allostasis;
 pub mod disturbance;
+pub mod dynamics;
+pub mod environment;
+pub mod forgetting;
 pub mod isf;
+pub mod behavior;
 pub mod bias;
-pub mod memory;
\ No newline at end of file
+pub mod caregiver;
+pub mod circadian;
+pub mod clustering;
+pub mod competition;
+pub mod development;
+pub mod genome;
+pub mod habituation;
+pub mod hormone;
+pub mod interoception;
+pub mod homeostasis;
+pub mod memory;
+pub mod metric;
+pub mod noise;
+pub mod plasticity;
+pub mod rng;
+pub mod state;
+pub mod timeline;
@@ -1,16 +1,159 @@
 // memory.rs
 
+use std::collections::VecDeque;
+
 use crate::disturbance::Disturbance;
+use crate::rng::SplitMix64;
+use crate::state::StateVector;
 
 #[derive(Clone, Debug)]
 pub struct ExperienceTrace {
-    pub tension_before: f32,
-    pub stability_before: f32,
-    pub energy_before: f32,
+    pub before: StateVector,
 
     pub disturbance: Disturbance,
 
-    pub tension_after: f32,
-    pub stability_after: f32,
-    pub energy_after: f32,
+    pub after: StateVector,
+
+    /// Times this episode was replayed while thinking
+    pub replays: u32,
+}
+
+impl ExperienceTrace {
+    /// Direction the internal state moved
+    pub fn delta(&self) -> StateVector {
+        self.after.delta(&self.before)
+    }
+
+    /// How much this episode moved the internal state
+    pub fn salience(&self) -> f32 {
+        self.after.l1(&self.before)
+    }
+}
+
+/// What happens when the episodic buffer is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    /// Oldest episode is forgotten first
+    Fifo,
+    /// Least salient episode is forgotten (new one is dropped if it is the weakest)
+    LowestSalience,
+    /// Uniform sample over everything ever seen (reservoir sampling)
+    Reservoir,
+}
+
+/// Fixed-capacity episodic memory
+///
+/// Short-lived, explicit store of raw experiences.
+/// Structural memory (biases) is the long-term goal,
+/// this only keeps enough episodes for trace replay.
+#[derive(Debug, Clone)]
+pub struct EpisodicBuffer {
+    traces: VecDeque<ExperienceTrace>,
+    capacity: usize,
+    policy: EvictionPolicy,
+
+    /// Total episodes offered (needed for reservoir sampling)
+    seen: u64,
+    rng: SplitMix64,
+}
+
+impl EpisodicBuffer {
+    pub const DEFAULT_CAPACITY: usize = 256;
+
+    pub fn new(capacity: usize, policy: EvictionPolicy) -> Self {
+        Self::with_seed(capacity, policy, 0)
+    }
+
+    pub fn with_seed(capacity: usize, policy: EvictionPolicy, seed: u64) -> Self {
+        Self {
+            traces: VecDeque::with_capacity(capacity),
+            capacity,
+            policy,
+            seen: 0,
+            rng: SplitMix64::new(seed),
+        }
+    }
+
+    pub fn record(&mut self, trace: ExperienceTrace) {
+        self.seen += 1;
+
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.traces.len() < self.capacity {
+            self.traces.push_back(trace);
+            return;
+        }
+
+        match self.policy {
+            EvictionPolicy::Fifo => {
+                self.traces.pop_front();
+                self.traces.push_back(trace);
+            }
+            EvictionPolicy::LowestSalience => {
+                let weakest = self
+                    .traces
+                    .iter()
+                    .enumerate()
+                    .min_by(|a, b| a.1.salience().total_cmp(&b.1.salience()))
+                    .map(|(i, t)| (i, t.salience()));
+
+                if let Some((i, s)) = weakest
+                    && trace.salience() > s
+                {
+                    self.traces.remove(i);
+                    self.traces.push_back(trace);
+                }
+            }
+            EvictionPolicy::Reservoir => {
+                let j = self.rng.below(self.seen) as usize;
+                if j < self.capacity {
+                    self.traces[j] = trace;
+                }
+            }
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ExperienceTrace> {
+        self.traces.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut ExperienceTrace> {
+        self.traces.iter_mut()
+    }
+
+    pub fn len(&self) -> usize {
+        self.traces.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.traces.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn policy(&self) -> EvictionPolicy {
+        self.policy
+    }
+
+    /// Episodes offered so far (stored or not)
+    pub fn seen(&self) -> u64 {
+        self.seen
+    }
+
+    /// Forget every episode and start counting afresh,
+    /// so reservoir sampling is uniform over the new day
+    pub fn clear(&mut self) {
+        self.traces.clear();
+        self.seen = 0;
+    }
+}
+
+impl Default for EpisodicBuffer {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY, EvictionPolicy::Fifo)
+    }
 }
@@ -0,0 +1,69 @@
+// metric.rs
+//
+// Distance between two points / directions in state space.
+// Shared by bias matching (similarity), basin distance and
+// clustering, so one choice changes the whole geometry.
+
+use std::fmt::Debug;
+
+pub trait Metric: Debug {
+    fn distance(&self, a: &[f32], b: &[f32]) -> f32;
+}
+
+/// Sum of absolute differences (original newborn)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct L1;
+
+impl Metric for L1 {
+    fn distance(&self, a: &[f32], b: &[f32]) -> f32 {
+        a.iter().zip(b.iter()).map(|(x, y)| (x - y).abs()).sum()
+    }
+}
+
+/// Euclidean
+#[derive(Debug, Clone, Copy, Default)]
+pub struct L2;
+
+impl Metric for L2 {
+    fn distance(&self, a: &[f32], b: &[f32]) -> f32 {
+        a.iter()
+            .zip(b.iter())
+            .map(|(x, y)| (x - y) * (x - y))
+            .sum::<f32>()
+            .sqrt()
+    }
+}
+
+/// 1 - cosine similarity (direction only, ignores magnitude)
+/// Zero vectors are only close to other zero vectors.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Cosine;
+
+impl Metric for Cosine {
+    fn distance(&self, a: &[f32], b: &[f32]) -> f32 {
+        let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+        let na = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let nb = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+        if na == 0.0 || nb == 0.0 {
+            return if na == nb { 0.0 } else { 1.0 };
+        }
+        1.0 - dot / (na * nb)
+    }
+}
+
+/// L1 with a weight per dimension (missing weights count as 1.0)
+#[derive(Debug, Clone, Default)]
+pub struct Scaled {
+    pub weights: Vec<f32>,
+}
+
+impl Metric for Scaled {
+    fn distance(&self, a: &[f32], b: &[f32]) -> f32 {
+        a.iter()
+            .zip(b.iter())
+            .enumerate()
+            .map(|(i, (x, y))| self.weights.get(i).copied().unwrap_or(1.0) * (x - y).abs())
+            .sum()
+    }
+}
@@ -0,0 +1,113 @@
+// noise.rs
+//
+// Spontaneous internal drift (proto-chaos)
+// ----------------------------------------
+// boredom, curiosity, mind wandering, spontaneous thought.
+// Without it the system freezes into a fixed point.
+//
+// All noise is seeded: same seed -> bit-identical trajectory.
+
+use std::fmt::Debug;
+
+use crate::rng::SplitMix64;
+use crate::state::StateVector;
+
+pub trait NoiseSource: Debug {
+    /// Next zero-mean sample (roughly unit scale)
+    fn sample(&mut self) -> f32;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoiseDistribution {
+    /// Flat in [-1, 1)
+    Uniform,
+    /// Standard normal
+    Gaussian,
+    /// 1/f noise (slow wandering + fast jitter)
+    Pink,
+}
+
+/// Built-in seeded noise (SplitMix64, no clock, no external crate)
+#[derive(Debug, Clone)]
+pub struct SeededNoise {
+    rng: SplitMix64,
+    distribution: NoiseDistribution,
+
+    // Pink filter memory (Paul Kellet's economy filter)
+    pink: [f32; 3],
+}
+
+impl SeededNoise {
+    pub fn new(seed: u64, distribution: NoiseDistribution) -> Self {
+        Self {
+            rng: SplitMix64::new(seed),
+            distribution,
+            pink: [0.0; 3],
+        }
+    }
+
+    fn gaussian(&mut self) -> f32 {
+        // Box-Muller (1 - u keeps ln away from 0)
+        let u1 = 1.0 - self.rng.next_f32();
+        let u2 = self.rng.next_f32();
+        (-2.0 * u1.ln()).sqrt() * (std::f32::consts::TAU * u2).cos()
+    }
+}
+
+impl NoiseSource for SeededNoise {
+    fn sample(&mut self) -> f32 {
+        match self.distribution {
+            NoiseDistribution::Uniform => self.rng.next_f32() * 2.0 - 1.0,
+            NoiseDistribution::Gaussian => self.gaussian(),
+            NoiseDistribution::Pink => {
+                let white = self.gaussian();
+                self.pink[0] = 0.99765 * self.pink[0] + white * 0.099_046;
+                self.pink[1] = 0.963 * self.pink[1] + white * 0.296_516_4;
+                self.pink[2] = 0.57 * self.pink[2] + white * 1.052_691_3;
+
+                // ~unit variance
+                (self.pink[0] + self.pink[1] + self.pink[2] + white * 0.1848) * 0.25
+            }
+        }
+    }
+}
+
+/// Mind wandering: small random perturbation of the internal state
+#[derive(Debug)]
+pub struct MindWandering {
+    pub source: Box<dyn NoiseSource>,
+
+    /// Scale of the perturbation
+    pub amplitude: f32,
+
+    /// Per-dimension multipliers (missing entries count as 1.0)
+    pub weights: Vec<f32>,
+}
+
+impl MindWandering {
+    pub fn new(source: Box<dyn NoiseSource>, amplitude: f32) -> Self {
+        Self {
+            source,
+            amplitude,
+            weights: Vec::new(),
+        }
+    }
+
+    /// Seeded drift with the same amplitude on every dimension
+    pub fn seeded(seed: u64, distribution: NoiseDistribution, amplitude: f32) -> Self {
+        Self::new(Box::new(SeededNoise::new(seed, distribution)), amplitude)
+    }
+
+    pub fn with_weights(mut self, weights: Vec<f32>) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    pub fn perturb(&mut self, s: &mut StateVector) {
+        // Fixed order -> reproducible
+        for (i, v) in s.iter_mut().enumerate() {
+            let w = self.weights.get(i).copied().unwrap_or(1.0);
+            *v += self.source.sample() * self.amplitude * w;
+        }
+    }
+}
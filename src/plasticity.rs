@@ -0,0 +1,213 @@
+// plasticity.rs
+//
+// Plasticity rules (LEARNING HOW TO REACT)
+// ----------------------------------------
+// Each rule looks at one experience (shock, calm) and the
+// state it left behind, and may retune the reaction rates.
+//
+// Rules are independent so experiments can add new ones
+// (sensitization, metaplasticity, age effects) or ablate
+// built-in ones by name.
+
+use std::fmt::Debug;
+
+use crate::genome::Genome;
+
+/// What a rule gets to see after a disturbance
+#[derive(Debug, Clone, Copy)]
+pub struct PlasticityInput {
+    /// Strong + sudden part of the disturbance
+    pub shock: f32,
+    /// Long + predictable part of the disturbance
+    pub calm: f32,
+
+    pub tension: f32,
+    pub stability: f32,
+    pub energy: f32,
+}
+
+/// The reaction rates a rule may adjust
+#[derive(Debug, Clone, Copy)]
+pub struct Plasticity {
+    pub shock_sensitivity: f32,
+    pub stability_gain_rate: f32,
+    pub energy_gain_rate: f32,
+}
+
+pub trait PlasticityRule: Debug {
+    /// Stable name, used to ablate rules in experiments
+    fn name(&self) -> &str;
+
+    fn adapt(&mut self, input: &PlasticityInput, p: &mut Plasticity);
+
+    /// May push shock sensitivity past its normal range
+    /// (up to the genome's hypervigilance limit)
+    fn allows_hypervigilance(&self) -> bool {
+        false
+    }
+}
+
+// --------------------------------------------------
+// BUILT-IN RULES (ORIGINAL NEWBORN)
+// --------------------------------------------------
+
+/// Repeated shock with good regulation → desensitization.
+/// Never acts on a hypervigilant brain (above `normal_max`):
+/// that only recovers through sustained calm.
+#[derive(Debug, Clone)]
+pub struct Desensitization {
+    pub shock_threshold: f32,
+    pub stability_threshold: f32,
+    pub factor: f32,
+    pub normal_max: f32,
+}
+
+impl PlasticityRule for Desensitization {
+    fn name(&self) -> &str {
+        "desensitization"
+    }
+
+    fn adapt(&mut self, input: &PlasticityInput, p: &mut Plasticity) {
+        if input.shock > self.shock_threshold
+            && input.stability > self.stability_threshold
+            && p.shock_sensitivity <= self.normal_max
+        {
+            p.shock_sensitivity *= self.factor;
+        }
+    }
+}
+
+/// Long calm exposure → faster regulation learning
+#[derive(Debug, Clone)]
+pub struct RegulationLearning {
+    pub calm_threshold: f32,
+    pub factor: f32,
+}
+
+impl PlasticityRule for RegulationLearning {
+    fn name(&self) -> &str {
+        "regulation_learning"
+    }
+
+    fn adapt(&mut self, input: &PlasticityInput, p: &mut Plasticity) {
+        if input.calm > self.calm_threshold {
+            p.stability_gain_rate *= self.factor;
+        }
+    }
+}
+
+/// Chronic overload → energy efficiency adjustment
+#[derive(Debug, Clone)]
+pub struct EnergyEfficiency {
+    pub tension_threshold: f32,
+    pub factor: f32,
+}
+
+impl PlasticityRule for EnergyEfficiency {
+    fn name(&self) -> &str {
+        "energy_efficiency"
+    }
+
+    fn adapt(&mut self, input: &PlasticityInput, p: &mut Plasticity) {
+        if input.tension > self.tension_threshold {
+            p.energy_gain_rate *= self.factor;
+        }
+    }
+}
+
+/// Repeated shock with poor regulation → sensitization (more reactive).
+/// Only sustained calm walks it back toward the birth sensitivity.
+///
+/// Opt-in (not in `default_rules`): push it onto `plasticity_rules`.
+#[derive(Debug, Clone)]
+pub struct Sensitization {
+    pub shock_threshold: f32,
+    pub stability_threshold: f32,
+    pub factor: f32,
+
+    /// Consecutive poorly-regulated shocks before sensitization starts
+    pub repeats: u32,
+
+    /// Calm above this (with no shock) counts as a calm experience
+    pub calm_threshold: f32,
+
+    /// Consecutive calm experiences before recovery starts
+    pub calm_needed: u32,
+
+    /// Fraction of the excess over `baseline` kept per calm experience
+    pub recovery: f32,
+
+    /// Sensitivity recovery heads back to
+    pub baseline: f32,
+
+    shock_streak: u32,
+    calm_streak: u32,
+}
+
+impl Sensitization {
+    pub fn from_genome(g: &Genome) -> Self {
+        Self {
+            shock_threshold: g.sensitize_shock,
+            stability_threshold: g.sensitize_stability,
+            factor: g.sensitize_factor,
+            repeats: g.sensitize_repeats,
+            calm_threshold: g.sensitize_calm,
+            calm_needed: g.sensitize_calm_needed,
+            recovery: g.sensitize_recovery,
+            baseline: g.shock_sensitivity,
+            shock_streak: 0,
+            calm_streak: 0,
+        }
+    }
+}
+
+impl PlasticityRule for Sensitization {
+    fn name(&self) -> &str {
+        "sensitization"
+    }
+
+    fn allows_hypervigilance(&self) -> bool {
+        true
+    }
+
+    fn adapt(&mut self, input: &PlasticityInput, p: &mut Plasticity) {
+        if input.shock > self.shock_threshold && input.stability < self.stability_threshold {
+            self.shock_streak += 1;
+            self.calm_streak = 0;
+            if self.shock_streak >= self.repeats {
+                p.shock_sensitivity *= self.factor;
+            }
+            return;
+        }
+
+        if input.calm > self.calm_threshold && input.shock <= self.shock_threshold {
+            self.shock_streak = 0;
+            self.calm_streak += 1;
+            if self.calm_streak >= self.calm_needed && p.shock_sensitivity > self.baseline {
+                p.shock_sensitivity = self.baseline + (p.shock_sensitivity - self.baseline) * self.recovery;
+            }
+        } else {
+            self.calm_streak = 0;
+        }
+    }
+}
+
+/// The three original rules, tuned by the genome
+pub fn default_rules(g: &Genome) -> Vec<Box<dyn PlasticityRule>> {
+    vec![
+        Box::new(Desensitization {
+            shock_threshold: g.desensitize_shock,
+            stability_threshold: g.desensitize_stability,
+            factor: g.desensitize_factor,
+            normal_max: g.shock_sensitivity_range.1,
+        }),
+        Box::new(RegulationLearning {
+            calm_threshold: g.calm_threshold,
+            factor: g.regulation_learning_factor,
+        }),
+        Box::new(EnergyEfficiency {
+            tension_threshold: g.overload_tension,
+            factor: g.energy_efficiency_factor,
+        }),
+    ]
+}
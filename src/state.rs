@@ -0,0 +1,183 @@
+// state.rs
+//
+// Internal state as a vector of named dimensions.
+//
+// The first three dimensions are ALWAYS the core physiology
+// (tension, stability, energy). Extra dimensions (hunger,
+// warmth, arousal, ...) are appended after them and take part
+// in biases, traces, similarity and clustering.
+
+use std::ops::{Deref, DerefMut};
+
+/// Nervous arousal / stress tone
+pub const TENSION: usize = 0;
+/// Regulation capacity (slow changing)
+pub const STABILITY: usize = 1;
+/// Metabolic / activation energy
+pub const ENERGY: usize = 2;
+
+/// Number of core physiology dimensions
+pub const CORE_DIMS: usize = 3;
+
+/// One named axis of the internal state
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dimension {
+    pub name: String,
+
+    /// Value at birth
+    pub birth: f32,
+
+    /// Biological limits (min, max)
+    pub range: (f32, f32),
+}
+
+impl Dimension {
+    pub fn new(name: &str, birth: f32, range: (f32, f32)) -> Self {
+        Self {
+            name: name.to_string(),
+            birth,
+            range,
+        }
+    }
+}
+
+/// The original newborn: tension, stability, energy
+pub fn newborn_dimensions() -> Vec<Dimension> {
+    vec![
+        Dimension::new("tension", 0.5, (0.0, 1.5)),   // neutral
+        Dimension::new("stability", 0.1, (0.0, 1.0)), // very low regulation
+        Dimension::new("energy", 0.5, (0.0, 1.0)),    // baseline vitality
+    ]
+}
+
+/// Values of every dimension (same order as the genome)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StateVector(pub Vec<f32>);
+
+impl StateVector {
+    pub fn zeros(dims: usize) -> Self {
+        Self(vec![0.0; dims])
+    }
+
+    pub fn tension(&self) -> f32 {
+        self.0[TENSION]
+    }
+
+    pub fn stability(&self) -> f32 {
+        self.0[STABILITY]
+    }
+
+    pub fn energy(&self) -> f32 {
+        self.0[ENERGY]
+    }
+
+    /// self + other * k (vector step, used by integrators)
+    pub fn add_scaled(&self, other: &StateVector, k: f32) -> StateVector {
+        StateVector(self.iter().zip(other.iter()).map(|(a, b)| a + b * k).collect())
+    }
+
+    /// self - before (direction of change)
+    pub fn delta(&self, before: &StateVector) -> StateVector {
+        StateVector(self.iter().zip(before.iter()).map(|(a, b)| a - b).collect())
+    }
+
+    /// Sum of absolute differences
+    pub fn l1(&self, other: &[f32]) -> f32 {
+        self.iter().zip(other.iter()).map(|(a, b)| (a - b).abs()).sum()
+    }
+}
+
+impl Deref for StateVector {
+    type Target = [f32];
+
+    fn deref(&self) -> &[f32] {
+        &self.0
+    }
+}
+
+impl DerefMut for StateVector {
+    fn deref_mut(&mut self) -> &mut [f32] {
+        &mut self.0
+    }
+}
+
+/// One observable frame of a simulation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    pub tick: u32,
+
+    pub state: StateVector,
+
+    pub shock_sensitivity: f32,
+    pub stability_gain_rate: f32,
+    pub energy_gain_rate: f32,
+
+    /// Steps lived so far
+    pub age: u64,
+
+    /// Slow mood integrating chronic strain
+    pub allostatic_load: f32,
+
+    /// Circulating stress hormone
+    pub hormone: f32,
+
+    /// Accumulated tiredness
+    pub fatigue: f32,
+
+    /// How strongly the body wants to sleep
+    pub sleep_pressure: f32,
+
+    /// (name, level) of every interoceptive drive
+    pub drives: Vec<(String, f32)>,
+
+    pub bias_count: usize,
+
+    /// Disturbances acting during this tick
+    pub active_disturbances: usize,
+
+    /// Basins pruned during this tick
+    pub pruned: usize,
+
+    /// Basins split in two during this tick
+    pub split: usize,
+}
+
+impl Snapshot {
+    pub fn tension(&self) -> f32 {
+        self.state.tension()
+    }
+
+    pub fn stability(&self) -> f32 {
+        self.state.stability()
+    }
+
+    pub fn energy(&self) -> f32 {
+        self.state.energy()
+    }
+}
+
+/// What happened during one step (disturbance or thinking tick)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StepReport {
+    /// Basins removed by forgetting
+    pub pruned: usize,
+
+    /// Blurred basins split into two children
+    pub split: usize,
+
+    /// Similar basins merged by clustering
+    pub merged: usize,
+
+    /// Internal disturbances emitted by drives
+    pub urges: usize,
+}
+
+impl StepReport {
+    /// Fold another step's counts into this one
+    pub fn absorb(&mut self, other: StepReport) {
+        self.pruned += other.pruned;
+        self.split += other.split;
+        self.merged += other.merged;
+        self.urges += other.urges;
+    }
+}
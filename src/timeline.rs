@@ -0,0 +1,174 @@
+// timeline.rs
+//
+// Timeline (LIFE AS A SCHEDULE)
+// -----------------------------
+// Time-stamped disturbances + idle periods, driven tick by tick.
+//
+// One tick = one time unit.
+// - ticks with active disturbances: physiology is integrated
+// - idle ticks: internal thinking
+//
+// Overlaps are allowed ("a loud noise while being rocked").
+// Each disturbance is learned from once, when it ends.
+
+use crate::disturbance::Disturbance;
+use crate::dynamics::{Overlap, TimeStep};
+use crate::isf::InternalStateField;
+use crate::state::{Snapshot, StateVector, StepReport};
+
+#[derive(Debug, Clone)]
+pub struct ScheduledDisturbance {
+    pub start: u32,
+    pub disturbance: Disturbance,
+}
+
+impl ScheduledDisturbance {
+    /// Ticks touched by this disturbance (at least one)
+    pub fn span(&self) -> u32 {
+        (self.disturbance.duration.ceil() as u32).max(1)
+    }
+
+    /// Last tick this disturbance is active
+    pub fn last_tick(&self) -> u32 {
+        self.start + self.span() - 1
+    }
+
+    /// Fraction of `tick` during which this disturbance acts
+    fn activity(&self, tick: u32) -> f32 {
+        if tick < self.start {
+            return 0.0;
+        }
+        let offset = (tick - self.start) as f32;
+        (self.disturbance.duration - offset).clamp(0.0, 1.0)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Timeline {
+    pub events: Vec<ScheduledDisturbance>,
+    pub overlap: Overlap,
+    pub step: TimeStep,
+
+    /// Where `then` / `rest` append
+    cursor: u32,
+}
+
+impl Timeline {
+    pub fn new() -> Self {
+        Self {
+            events: Vec::new(),
+            overlap: Overlap::Sum,
+            step: TimeStep::default(),
+            cursor: 0,
+        }
+    }
+
+    /// Schedule `d` at an absolute tick (may overlap others)
+    pub fn at(mut self, tick: u32, d: Disturbance) -> Self {
+        self.events.push(ScheduledDisturbance {
+            start: tick,
+            disturbance: d,
+        });
+        self
+    }
+
+    /// Schedule `d` right after everything appended so far
+    pub fn then(mut self, d: Disturbance) -> Self {
+        let event = ScheduledDisturbance {
+            start: self.cursor,
+            disturbance: d,
+        };
+        self.cursor += event.span();
+        self.events.push(event);
+        self
+    }
+
+    /// Idle period (pure thinking)
+    pub fn rest(mut self, ticks: u32) -> Self {
+        self.cursor += ticks;
+        self
+    }
+
+    pub fn overlap(mut self, overlap: Overlap) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    pub fn time_step(mut self, step: TimeStep) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Total ticks
+    pub fn len(&self) -> u32 {
+        self.events
+            .iter()
+            .map(|e| e.last_tick() + 1)
+            .fold(self.cursor, u32::max)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drive `isf` through the whole timeline, one snapshot per tick
+    pub fn run(&self, isf: &mut InternalStateField) -> Vec<Snapshot> {
+        let mut snapshots = Vec::with_capacity(self.len() as usize);
+        let mut starts: Vec<Option<StateVector>> = vec![None; self.events.len()];
+
+        for tick in 0..self.len() {
+            let mut active = Vec::new();
+            let mut report = StepReport::default();
+
+            for (i, e) in self.events.iter().enumerate() {
+                if e.start == tick {
+                    // Zero-length events are plain jumps
+                    if e.disturbance.duration <= 0.0 {
+                        report.absorb(isf.feel(&e.disturbance));
+                        continue;
+                    }
+                    starts[i] = Some(isf.state.clone());
+                }
+
+                let w = e.activity(tick);
+                if w > 0.0 {
+                    active.push((&e.disturbance, w));
+                }
+            }
+
+            if active.is_empty() {
+                report.absorb(isf.internal_thinking_tick());
+            } else {
+                let steps = self.step.steps(1.0);
+                let h = 1.0 / steps as f32;
+                for _ in 0..steps {
+                    isf.integrate_combined(&active, self.overlap, h, self.step.integrator);
+                }
+                isf.elapse(false);
+            }
+
+            // Learn from everything that just ended
+            for (i, e) in self.events.iter().enumerate() {
+                if e.last_tick() == tick
+                    && let Some(before) = starts[i].take()
+                {
+                    report.absorb(isf.learn_from_experience(before, &e.disturbance));
+                }
+            }
+
+            let mut snap = isf.snapshot(tick);
+            snap.active_disturbances = active.len();
+            snap.pruned = report.pruned;
+            snap.split = report.split;
+            snapshots.push(snap);
+        }
+
+        snapshots
+    }
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -20,5 +20,5 @@ fn dominant_bias_controls_thinking() {
         .max_by(|a, b| a.strength.partial_cmp(&b.strength).unwrap())
         .unwrap();
 
-    assert!(dominant.ds_pref.abs() > dominant.dt_pref.abs());
+    assert!(dominant.ds_pref().abs() > dominant.dt_pref().abs());
 }
@@ -0,0 +1,95 @@
+use project_newborn::allostasis::Allostasis;
+use project_newborn::disturbance::Disturbance;
+use project_newborn::isf::InternalStateField;
+use project_newborn::state::{ENERGY, TENSION};
+
+fn stressed() -> InternalStateField {
+    let mut isf = InternalStateField::new();
+    isf.allostasis = Some(Allostasis::default());
+    isf
+}
+
+fn slam() -> Disturbance {
+    Disturbance::new(0.9, 0.1, 0.9)
+}
+
+#[test]
+fn load_is_zero_when_not_modelled() {
+    let mut isf = InternalStateField::new();
+    for _ in 0..50 {
+        isf.apply_disturbance(&slam());
+    }
+    assert_eq!(isf.allostatic_load(), 0.0);
+    assert_eq!(isf.snapshot(0).allostatic_load, 0.0);
+}
+
+#[test]
+fn chronic_tension_builds_load_slowly() {
+    let mut isf = stressed();
+    isf.state[TENSION] = 1.5;
+
+    isf.internal_thinking_tick();
+    let early = isf.allostatic_load();
+    assert!(early > 0.0 && early < 0.05);
+
+    for _ in 0..300 {
+        isf.state[TENSION] = 1.5;
+        isf.internal_thinking_tick();
+    }
+    assert!(isf.allostatic_load() > 0.5);
+    assert_eq!(isf.snapshot(0).allostatic_load, isf.allostatic_load());
+}
+
+#[test]
+fn load_drains_only_slowly_during_relief() {
+    let mut isf = stressed();
+    isf.allostasis.as_mut().unwrap().load = 0.8;
+    isf.state[TENSION] = 0.2;
+    isf.state[ENERGY] = 0.5;
+
+    for _ in 0..10 {
+        isf.internal_thinking_tick();
+    }
+    let after_short_rest = isf.allostatic_load();
+    assert!(after_short_rest > 0.7);
+
+    for _ in 0..1000 {
+        isf.internal_thinking_tick();
+    }
+    assert!(isf.allostatic_load() < after_short_rest);
+}
+
+#[test]
+fn load_feeds_back_into_physiology() {
+    let mut worn = stressed();
+    worn.allostasis.as_mut().unwrap().load = 1.0;
+    let mut rested = stressed();
+
+    let calm = Disturbance::new(0.3, 2.0, 0.1);
+    worn.apply_disturbance(&slam());
+    rested.apply_disturbance(&slam());
+    assert!(worn.tension() > rested.tension());
+
+    worn.apply_disturbance(&calm);
+    rested.apply_disturbance(&calm);
+    assert!(worn.stability() < rested.stability());
+
+    // The underlying (learned) rates are untouched
+    assert_eq!(worn.shock_sensitivity, rested.shock_sensitivity);
+}
+
+#[test]
+fn load_raises_baseline_tension() {
+    let mut worn = stressed();
+    worn.allostasis.as_mut().unwrap().load = 1.0;
+    let mut rested = stressed();
+    worn.state[TENSION] = 0.1;
+    rested.state[TENSION] = 0.1;
+
+    for _ in 0..20 {
+        worn.internal_thinking_tick();
+        rested.internal_thinking_tick();
+    }
+
+    assert!(worn.tension() > rested.tension());
+}
@@ -0,0 +1,58 @@
+use project_newborn::{isf::InternalStateField, disturbance::Disturbance};
+use project_newborn::bias::BiasSemantics;
+use project_newborn::state::TENSION;
+
+fn calm_raised(semantics: BiasSemantics) -> InternalStateField {
+    let mut brain = InternalStateField::new();
+    brain.bias_semantics = semantics;
+
+    let calm = Disturbance::new(0.3, 3.0, 0.1);
+    for _ in 0..5 {
+        brain.apply_disturbance(&calm);
+    }
+    brain
+}
+
+#[test]
+fn direction_bias_saturates() {
+    let mut brain = calm_raised(BiasSemantics::Direction);
+
+    for _ in 0..2000 {
+        brain.internal_thinking_tick();
+    }
+
+    assert_eq!(brain.stability(), 1.0, "Direction replay should run into the clamp");
+}
+
+#[test]
+fn attractor_bias_settles_at_target() {
+    let mut brain = calm_raised(BiasSemantics::Attractor { pull: 0.1 });
+
+    let target = brain.biases[0].target.clone();
+    assert!(target[1] < 1.0);
+
+    for _ in 0..2000 {
+        brain.internal_thinking_tick();
+    }
+
+    let before = brain.state.clone();
+    brain.internal_thinking_tick();
+
+    assert!(brain.state.l1(&before) < 1e-3, "Attractor replay did not settle");
+    assert!(brain.stability() < 1.0, "Attractor replay saturated");
+}
+
+#[test]
+fn attractor_pull_scales_with_strength() {
+    let mut weak = calm_raised(BiasSemantics::Attractor { pull: 0.1 });
+    let mut strong = calm_raised(BiasSemantics::Attractor { pull: 0.1 });
+    weak.biases[0].strength = 0.1;
+    strong.biases[0].strength = 0.9;
+
+    weak.state[TENSION] = 1.4;
+    strong.state[TENSION] = 1.4;
+    weak.bias_thinking_tick();
+    strong.bias_thinking_tick();
+
+    assert!(strong.tension() < weak.tension());
+}
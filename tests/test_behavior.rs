@@ -0,0 +1,99 @@
+use project_newborn::behavior::{Action, Behavior, BehaviorInput, Policy};
+use project_newborn::circadian::Circadian;
+use project_newborn::disturbance::Disturbance;
+use project_newborn::interoception::{Drive, Interoception};
+use project_newborn::isf::InternalStateField;
+use project_newborn::state::TENSION;
+
+#[test]
+fn high_tension_makes_the_newborn_cry() {
+    let mut isf = InternalStateField::new();
+    isf.state[TENSION] = 1.4;
+
+    let action = isf.act();
+
+    assert_eq!(action.behavior, Behavior::Cry);
+    assert!(action.intensity > 0.5);
+    assert_eq!(isf.last_action, Some(action));
+}
+
+#[test]
+fn crying_drains_energy() {
+    let mut isf = InternalStateField::new();
+    isf.state[TENSION] = 1.4;
+    let before = isf.energy();
+
+    for _ in 0..5 {
+        isf.act();
+    }
+
+    assert!(isf.energy() < before);
+}
+
+#[test]
+fn settled_newborn_is_calm_for_free() {
+    let mut isf = InternalStateField::new();
+    isf.state[TENSION] = 0.2;
+    let before = isf.state.clone();
+
+    assert_eq!(isf.act().behavior, Behavior::Calm);
+    assert_eq!(isf.state, before);
+}
+
+#[test]
+fn alert_but_not_distressed_orients() {
+    let mut isf = InternalStateField::new();
+    isf.state[TENSION] = 0.75;
+    assert_eq!(isf.act().behavior, Behavior::Orient);
+}
+
+#[test]
+fn hunger_makes_the_newborn_seek() {
+    let mut isf = InternalStateField::new();
+    isf.interoception = Interoception::default().with_drive(Drive::hunger());
+    isf.interoception.drive_mut("hunger").unwrap().level = 0.8;
+
+    let action = isf.act();
+    assert_eq!(action.behavior, Behavior::Seek);
+    assert!((action.intensity - 0.8).abs() < 1e-6);
+}
+
+#[test]
+fn night_time_sleep_pressure_makes_it_sleep() {
+    let mut isf = InternalStateField::new();
+    let mut clock = Circadian::new(240.0, 180.0);
+    clock.fatigue = 1.0;
+    isf.circadian = Some(clock);
+
+    assert_eq!(isf.act().behavior, Behavior::Sleep);
+}
+
+#[test]
+fn comfort_basin_drives_approach() {
+    let mut isf = InternalStateField::new();
+    for _ in 0..10 {
+        isf.apply_disturbance(&Disturbance::new(0.3, 2.0, 0.1).with_valence(1.0));
+    }
+    isf.state[TENSION] = 0.3;
+
+    assert_eq!(isf.act().behavior, Behavior::Seek);
+}
+
+/// Always cries, however the newborn feels
+#[derive(Debug)]
+struct Colicky;
+
+impl Policy for Colicky {
+    fn act(&mut self, _: &BehaviorInput) -> Action {
+        Action::new(Behavior::Cry, 1.0)
+    }
+}
+
+#[test]
+fn policy_is_configurable() {
+    let mut isf = InternalStateField::new();
+    isf.state[TENSION] = 0.1;
+    isf.policy = Box::new(Colicky);
+
+    assert_eq!(isf.act().behavior, Behavior::Cry);
+}
@@ -0,0 +1,129 @@
+use project_newborn::bias::{BiasField, Splitting};
+use project_newborn::disturbance::Disturbance;
+use project_newborn::isf::InternalStateField;
+
+fn absorb(deltas: &[[f32; 3]]) -> BiasField {
+    let mut b = BiasField::new(3);
+    for d in deltas {
+        b.reinforce(d);
+    }
+    b
+}
+
+fn batch_covariance(deltas: &[[f32; 3]], i: usize, j: usize) -> f32 {
+    let n = deltas.len() as f32;
+    let mi = deltas.iter().map(|d| d[i]).sum::<f32>() / n;
+    let mj = deltas.iter().map(|d| d[j]).sum::<f32>() / n;
+    deltas.iter().map(|d| (d[i] - mi) * (d[j] - mj)).sum::<f32>() / (n - 1.0)
+}
+
+const DELTAS: [[f32; 3]; 6] = [
+    [0.10, 0.02, -0.01],
+    [0.12, 0.01, 0.00],
+    [0.08, 0.03, -0.02],
+    [-0.05, 0.04, 0.01],
+    [0.11, -0.01, 0.02],
+    [0.09, 0.02, -0.03],
+];
+
+#[test]
+fn running_statistics_match_batch() {
+    let b = absorb(&DELTAS);
+
+    for i in 0..3 {
+        let mean = DELTAS.iter().map(|d| d[i]).sum::<f32>() / DELTAS.len() as f32;
+        assert!((b.mean[i] - mean).abs() < 1e-6);
+        for j in 0..3 {
+            assert!((b.covariance(i, j) - batch_covariance(&DELTAS, i, j)).abs() < 1e-6);
+        }
+    }
+}
+
+#[test]
+fn merged_statistics_equal_pooled_statistics() {
+    let mut a = absorb(&DELTAS[..2]);
+    let b = absorb(&DELTAS[2..]);
+    a.merge(&b);
+
+    let pooled = absorb(&DELTAS);
+    assert_eq!(a.count, pooled.count);
+    for i in 0..3 {
+        assert!((a.mean[i] - pooled.mean[i]).abs() < 1e-6);
+        for j in 0..3 {
+            assert!((a.covariance(i, j) - pooled.covariance(i, j)).abs() < 1e-6);
+        }
+    }
+}
+
+#[test]
+fn consistent_basins_are_more_confident() {
+    let consistent = absorb(&[[0.1, 0.0, 0.0]; 6]);
+    let blurred = absorb(&[
+        [0.1, 0.0, 0.0],
+        [-0.1, 0.0, 0.0],
+        [0.1, 0.0, 0.0],
+        [-0.1, 0.0, 0.0],
+        [0.1, 0.0, 0.0],
+        [-0.05, 0.0, 0.0],
+    ]);
+    let young = absorb(&[[0.1, 0.0, 0.0]]);
+
+    assert!(consistent.confidence() > blurred.confidence());
+    assert!(consistent.confidence() > young.confidence());
+    assert!(consistent.confidence() <= 1.0);
+}
+
+#[test]
+fn split_separates_children_along_principal_axis() {
+    let mut deltas = Vec::new();
+    for k in 0..8 {
+        let s = if k % 2 == 0 { 1.0 } else { -1.0 };
+        deltas.push([0.1 * s, 0.0, 0.1 * s]);
+    }
+    let b = absorb(&deltas);
+    assert!(b.should_split(&Splitting::default()));
+
+    let (left, right) = b.split();
+
+    // Opposite sides along the (tension, energy) diagonal
+    assert!(left.mean[0] * right.mean[0] < 0.0);
+    assert!(left.mean[2] * right.mean[2] < 0.0);
+    assert!(left.mean[1].abs() < 1e-6);
+
+    assert!((left.strength + right.strength - b.strength).abs() < 1e-6);
+    assert_eq!(left.total_variance(), 0.0);
+    assert!(!left.should_split(&Splitting::default()));
+}
+
+#[test]
+fn field_splits_blurred_basin_and_reports_it() {
+    let mut isf = InternalStateField::new();
+    isf.splitting = Some(Splitting {
+        variance_threshold: 0.0005,
+        min_count: 4,
+    });
+
+    let jolt = Disturbance::new(0.6, 0.2, 0.9);
+    let soothe = Disturbance::new(0.2, 0.5, 0.1);
+
+    let mut split = 0;
+    for i in 0..20 {
+        let d = if i % 2 == 0 { &jolt } else { &soothe };
+        split += isf.apply_disturbance(d).split;
+    }
+
+    assert!(split > 0);
+    assert!(isf.biases.len() >= 2);
+}
+
+#[test]
+fn splitting_is_off_by_default() {
+    let mut isf = InternalStateField::new();
+    let jolt = Disturbance::new(0.6, 0.2, 0.9);
+    let soothe = Disturbance::new(0.2, 0.5, 0.1);
+
+    for i in 0..20 {
+        let d = if i % 2 == 0 { &jolt } else { &soothe };
+        assert_eq!(isf.apply_disturbance(d).split, 0);
+    }
+}
@@ -0,0 +1,114 @@
+use project_newborn::behavior::{Action, Behavior};
+use project_newborn::caregiver::{Caregiver, CaregiverProfile};
+use project_newborn::disturbance::Disturbance;
+use project_newborn::environment::{Combined, Environment, RandomEvents, Runner};
+use project_newborn::isf::InternalStateField;
+use project_newborn::state::{Snapshot, StateVector, STABILITY, TENSION};
+
+/// Same harsh world, different caregivers
+fn raise(caregiver: Caregiver) -> (InternalStateField, f32, f32) {
+    let mut isf = InternalStateField::new();
+    let mut env = Combined::new()
+        .with(RandomEvents::new(42, 0.15))
+        .with(caregiver);
+
+    let history = Runner::new().run(&mut isf, &mut env, 600);
+    let n = history.len() as f32;
+    let mean_tension = history.iter().map(|r| r.snapshot.tension()).sum::<f32>() / n;
+    let mean_stability = history.iter().map(|r| r.snapshot.stability()).sum::<f32>() / n;
+    (isf, mean_tension, mean_stability)
+}
+
+#[test]
+fn sensitive_care_keeps_tension_lower_than_neglect() {
+    let (_, sensitive, _) = raise(Caregiver::sensitive(1));
+    let (_, neglected, _) = raise(Caregiver::neglectful(1));
+
+    assert!(sensitive < neglected);
+}
+
+#[test]
+fn care_shapes_stability_and_reactivity() {
+    let (cared, _, cared_stability) = raise(Caregiver::sensitive(1));
+    let (neglected, _, neglected_stability) = raise(Caregiver::neglectful(1));
+
+    assert!(cared_stability > neglected_stability);
+    assert!(cared.shock_sensitivity < neglected.shock_sensitivity);
+}
+
+#[test]
+fn sensitive_care_leaves_comfort_basins() {
+    let (cared, _, _) = raise(Caregiver::sensitive(1));
+
+    assert!(cared.biases.iter().any(|b| b.tendency() > 0.0));
+}
+
+#[test]
+fn profiles_differ_in_how_often_they_answer() {
+    let answered = |mut c: Caregiver| {
+        let snap = InternalStateField::new().snapshot(0);
+        let mut state = snap.state.clone();
+        let cry = Action::new(Behavior::Cry, 1.0);
+        for _ in 0..2000 {
+            c.step(&snap, Some(&cry));
+            c.regulate(&mut state);
+        }
+        c.responses as f32 / (c.responses + c.ignored) as f32
+    };
+
+    let sensitive = answered(Caregiver::sensitive(3));
+    let inconsistent = answered(Caregiver::inconsistent(3));
+    let neglectful = answered(Caregiver::neglectful(3));
+
+    assert!(sensitive > inconsistent && inconsistent > neglectful);
+}
+
+#[test]
+fn holding_regulates_tension_and_stability() {
+    let mut c = Caregiver::new(CaregiverProfile { latency: 0, ..CaregiverProfile::sensitive() }, 0);
+    let mut isf = InternalStateField::new();
+    isf.state[TENSION] = 1.2;
+
+    // Noticed and arrives right away
+    let touch = c.step(&isf.snapshot(0), None);
+    assert_eq!(touch.len(), 1);
+    assert!(touch[0].valence > 0.0);
+    assert!(c.is_holding());
+
+    let mut state = isf.state.clone();
+    c.regulate(&mut state);
+    assert!(state.tension() < isf.tension());
+    assert!(state.stability() > isf.stability());
+}
+
+#[test]
+fn caregivers_are_deterministic() {
+    let (a, _, _) = raise(Caregiver::inconsistent(9));
+    let (b, _, _) = raise(Caregiver::inconsistent(9));
+    assert_eq!(a.state, b.state);
+}
+
+/// Holds far too hard
+#[derive(Debug)]
+struct Overbearing;
+
+impl Environment for Overbearing {
+    fn step(&mut self, _: &Snapshot, _: Option<&Action>) -> Vec<Disturbance> {
+        Vec::new()
+    }
+
+    fn regulate(&mut self, state: &mut StateVector) {
+        state[TENSION] = -3.0;
+        state[STABILITY] = 5.0;
+    }
+}
+
+#[test]
+fn regulation_respects_biological_limits() {
+    let mut isf = InternalStateField::new();
+    let history = Runner::new().run(&mut isf, &mut Overbearing, 3);
+
+    for (v, dim) in history[2].snapshot.state.iter().zip(isf.genome.dimensions.iter()) {
+        assert!(*v >= dim.range.0 && *v <= dim.range.1, "{} left its range", dim.name);
+    }
+}
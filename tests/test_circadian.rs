@@ -0,0 +1,94 @@
+use project_newborn::circadian::Circadian;
+use project_newborn::disturbance::Disturbance;
+use project_newborn::environment::{Runner, Scripted};
+use project_newborn::isf::InternalStateField;
+use project_newborn::timeline::Timeline;
+
+fn clocked(phase: f32) -> InternalStateField {
+    let mut isf = InternalStateField::new();
+    isf.circadian = Some(Circadian::new(240.0, phase));
+    isf
+}
+
+#[test]
+fn drive_is_periodic() {
+    let at = |clock| Circadian { clock, ..Circadian::new(100.0, 0.0) }.drive();
+    assert!((at(25) - 1.0).abs() < 1e-5);
+    assert!((at(75) + 1.0).abs() < 1e-5);
+    assert!((at(10) - at(110)).abs() < 1e-4);
+}
+
+#[test]
+fn energy_shows_day_night_structure() {
+    let mut isf = clocked(0.0);
+    let mut energy = Vec::new();
+    for _ in 0..480 {
+        isf.internal_thinking_tick();
+        energy.push(isf.energy());
+    }
+
+    // Late afternoon of day two vs late night of day two
+    assert!(energy[300] > energy[420]);
+    assert_eq!(isf.snapshot(0).fatigue, 0.0);
+}
+
+#[test]
+fn same_shock_hits_harder_at_night() {
+    let slam = Disturbance::new(0.9, 0.1, 0.9);
+
+    let mut midday = clocked(60.0);
+    let mut midnight = clocked(180.0);
+
+    midday.apply_disturbance(&slam);
+    midnight.apply_disturbance(&slam);
+
+    assert!(midnight.tension() > midday.tension());
+}
+
+#[test]
+fn fatigue_rises_with_activity_and_sleep_clears_it() {
+    let mut isf = clocked(60.0);
+    for _ in 0..20 {
+        isf.apply_disturbance(&Disturbance::new(0.5, 1.0, 0.3));
+    }
+    let tired = isf.circadian.as_ref().unwrap().fatigue;
+    assert!(tired > 0.3);
+
+    let mut awake = clocked(60.0);
+    awake.circadian.as_mut().unwrap().fatigue = tired;
+    for _ in 0..20 {
+        awake.internal_thinking_tick();
+    }
+
+    isf.sleep(20);
+
+    let slept = isf.circadian.as_ref().unwrap().fatigue;
+    assert!(slept < awake.circadian.as_ref().unwrap().fatigue);
+    assert!(slept < tired * 0.5);
+}
+
+#[test]
+fn sleep_pressure_builds_at_night_and_with_fatigue() {
+    let at = |clock| Circadian { clock, ..Circadian::new(240.0, 0.0) };
+    assert!(at(180).sleep_pressure() > at(60).sleep_pressure());
+
+    let mut tired = at(60);
+    tired.fatigue = 0.8;
+    assert!(tired.sleep_pressure() > at(60).sleep_pressure());
+
+    assert_eq!(InternalStateField::new().sleep_pressure(), 0.0);
+}
+
+#[test]
+fn clock_ticks_once_per_tick_however_busy() {
+    // Half a day of rocking is still half a day
+    let mut rocked = clocked(0.0);
+    let ticks = Timeline::new().at(0, Disturbance::new(0.3, 120.0, 0.1)).run(&mut rocked).len();
+    assert_eq!(rocked.circadian.as_ref().unwrap().clock, ticks as u64);
+
+    // Five disturbances in one tick are one tick
+    let mut busy = clocked(0.0);
+    let mut env = Scripted::new(vec![vec![Disturbance::new(0.5, 1.0, 0.3); 5]]);
+    Runner::passive().run(&mut busy, &mut env, 1);
+    assert_eq!(busy.circadian.as_ref().unwrap().clock, 1);
+}
@@ -0,0 +1,137 @@
+use project_newborn::{isf::InternalStateField, disturbance::Disturbance};
+use project_newborn::bias::BiasField;
+use project_newborn::clustering::{Clustering, Linkage};
+use project_newborn::metric::{Cosine, Metric, Scaled, L1, L2};
+use project_newborn::rng::SplitMix64;
+
+fn basin(pref: [f32; 3], strength: f32) -> BiasField {
+    let mut b = BiasField::new(3);
+    b.pref = pref.to_vec();
+    b.strength = strength;
+    b
+}
+
+fn random_basins(n: usize, seed: u64) -> Vec<BiasField> {
+    let mut rng = SplitMix64::new(seed);
+    (0..n)
+        .map(|_| {
+            basin(
+                [rng.next_f32() * 0.6, rng.next_f32() * 0.6, rng.next_f32() * 0.6],
+                0.05 + rng.next_f32() * 0.1,
+            )
+        })
+        .collect()
+}
+
+fn sorted_prefs(biases: &[BiasField]) -> Vec<Vec<f32>> {
+    let mut prefs: Vec<Vec<f32>> = biases.iter().map(|b| b.pref.clone()).collect();
+    prefs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    prefs
+}
+
+#[test]
+fn clustering_is_order_independent() {
+    let clustering = Clustering::new(0.1, 5);
+
+    let mut forward = random_basins(200, 11);
+    let mut backward: Vec<BiasField> = forward.iter().rev().cloned().collect();
+
+    clustering.cluster(&mut forward, &L1);
+    clustering.cluster(&mut backward, &L1);
+
+    assert_eq!(forward.len(), backward.len());
+    for (a, b) in sorted_prefs(&forward).iter().zip(sorted_prefs(&backward).iter()) {
+        assert!(L1.distance(a, b) < 1e-4);
+    }
+}
+
+#[test]
+fn single_linkage_chains_complete_does_not() {
+    // Points 0.05 apart along one axis: neighbours merge, ends are far
+    let chain: Vec<BiasField> = (0..10).map(|i| basin([0.05 * i as f32, 0.0, 0.0], 0.1)).collect();
+
+    let mut single = chain.clone();
+    let mut complete = chain;
+
+    Clustering { linkage: Linkage::Single, ..Clustering::new(0.06, 0) }.cluster(&mut single, &L1);
+    Clustering { linkage: Linkage::Complete, ..Clustering::new(0.06, 0) }.cluster(&mut complete, &L1);
+
+    assert_eq!(single.len(), 1);
+    assert!(complete.len() >= 5);
+}
+
+#[test]
+fn clustering_scales_to_thousands() {
+    let mut biases = random_basins(2000, 5);
+    let merges = Clustering::new(0.1, 5).cluster(&mut biases, &L2);
+
+    assert_eq!(biases.len() + merges, 2000);
+    assert!(biases.len() < 500);
+}
+
+#[test]
+fn merge_into_only_touches_the_given_basin() {
+    // Two far-apart close pairs; only the pair around index 0 merges
+    let mut biases = vec![
+        basin([0.0, 0.0, 0.0], 0.1),
+        basin([0.5, 0.0, 0.0], 0.1),
+        basin([0.02, 0.0, 0.0], 0.1),
+        basin([0.52, 0.0, 0.0], 0.1),
+    ];
+
+    let merges = Clustering::new(0.1, 0).merge_into(&mut biases, 0, &L1);
+
+    assert_eq!(merges, 1);
+    assert_eq!(biases.len(), 3);
+    assert!(L1.distance(&biases[1].pref, &biases[2].pref) < 0.1, "Untouched pair merged");
+}
+
+#[test]
+fn pruning_a_lower_basin_does_not_misdirect_the_merge() {
+    let calm = Disturbance::new(0.3, 2.0, 0.1);
+
+    // Direction a calm experience carves
+    let mut probe = InternalStateField::new();
+    let before = probe.state.clone();
+    probe.apply_disturbance(&calm);
+    let delta = probe.state.delta(&before);
+    let d = [delta[0], delta[1], delta[2]];
+
+    let mut brain = InternalStateField::new();
+    brain.forgetting.prune_floor = 0.05;
+    brain.clustering.trigger = 0;
+    brain.biases = vec![
+        basin([0.5, 0.5, 0.5], 0.03), // dead, pruned this step
+        basin(d, 0.5),
+        basin([-0.5, -0.5, -0.5], 0.5), // far from everything
+        basin([d[0] + 0.011, d[1], d[2]], 0.5), // twin of calm
+    ];
+
+    let report = brain.apply_disturbance(&calm);
+
+    assert_eq!(report.pruned, 1);
+    assert_eq!(report.merged, 1, "Twin was not merged into the reinforced basin");
+    assert_eq!(brain.biases.len(), 2);
+}
+
+#[test]
+fn metrics_behave() {
+    let a = [0.1, 0.2, 0.0];
+    let b = [0.2, 0.4, 0.0];
+
+    assert!((L1.distance(&a, &b) - 0.3).abs() < 1e-6);
+    assert!((L2.distance(&[0.0, 0.0], &[0.3, 0.4]) - 0.5).abs() < 1e-6);
+    assert!(Cosine.distance(&a, &b).abs() < 1e-6, "Same direction = zero cosine distance");
+    assert!((Cosine.distance(&[1.0, 0.0], &[-1.0, 0.0]) - 2.0).abs() < 1e-6);
+
+    let tension_only = Scaled { weights: vec![1.0, 0.0, 0.0] };
+    assert!((tension_only.distance(&a, &b) - 0.1).abs() < 1e-6);
+}
+
+#[test]
+fn bias_similarity_uses_metric() {
+    let b = basin([0.1, 0.2, 0.0], 0.5);
+
+    assert_eq!(b.similarity(&[0.2, 0.4, 0.0]), b.similarity_with(&[0.2, 0.4, 0.0], &L1));
+    assert!(b.similarity_with(&[0.2, 0.4, 0.0], &Cosine) < 1e-6);
+}
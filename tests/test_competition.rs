@@ -0,0 +1,119 @@
+use project_newborn::{isf::InternalStateField, disturbance::Disturbance};
+use project_newborn::bias::BiasField;
+use project_newborn::competition::{BiasCompetition, CompetitionMode};
+use project_newborn::metric::{Cosine, L1};
+
+fn counts(competition: &mut BiasCompetition, fitness: &[f32], n: usize) -> Vec<usize> {
+    let mut c = vec![0; fitness.len()];
+    for _ in 0..n {
+        c[competition.select(fitness).unwrap()] += 1;
+    }
+    c
+}
+
+#[test]
+fn winner_take_all_skips_nan() {
+    let mut wta = BiasCompetition::default();
+
+    assert_eq!(wta.select(&[0.2, f32::NAN, 0.9, 0.1]), Some(2));
+    assert_eq!(wta.select(&[f32::NAN]), None);
+    assert_eq!(wta.select(&[]), None);
+}
+
+#[test]
+fn temperature_controls_sharpness() {
+    let fitness = [0.2, 0.5, 0.4];
+
+    let mut cold = BiasCompetition::new(CompetitionMode::Softmax { temperature: 0.001 }, 1);
+    let mut hot = BiasCompetition::new(CompetitionMode::Softmax { temperature: 100.0 }, 1);
+
+    assert_eq!(counts(&mut cold, &fitness, 1000), [0, 1000, 0]);
+    assert!(counts(&mut hot, &fitness, 3000).iter().all(|c| *c > 800));
+}
+
+#[test]
+fn proportional_sampling_follows_fitness() {
+    let mut prop = BiasCompetition::new(CompetitionMode::Proportional, 3);
+    let c = counts(&mut prop, &[0.1, 0.0, 0.3], 4000);
+
+    assert_eq!(c[1], 0);
+    assert!((c[2] as f32 / c[0] as f32 - 3.0).abs() < 0.5);
+}
+
+#[test]
+fn lateral_inhibition_suppresses_similar_neighbour() {
+    let mut a = BiasField::new(3);
+    a.pref = vec![0.1, 0.1, 0.0];
+    let mut b = BiasField::new(3);
+    b.pref = vec![0.11, 0.1, 0.0];
+    let mut far = BiasField::new(3);
+    far.pref = vec![-1.0, 0.0, 0.0];
+
+    let competition = BiasCompetition::default().with_inhibition(1.0, 0.1);
+    let mut fitness = vec![0.9, 0.5, 0.5];
+    competition.inhibit(&mut fitness, &[&a, &b, &far], &L1);
+
+    assert_eq!(fitness[1], 0.0, "Weaker twin was not suppressed");
+    assert!(fitness[2] > 0.49, "Distant basin should be left alone");
+}
+
+#[test]
+fn lateral_inhibition_uses_the_metric() {
+    // Same direction, different magnitude: far under L1, identical under cosine
+    let mut a = BiasField::new(3);
+    a.pref = vec![0.1, 0.1, 0.0];
+    let mut b = BiasField::new(3);
+    b.pref = vec![0.3, 0.3, 0.0];
+
+    let competition = BiasCompetition::default().with_inhibition(1.0, 0.1);
+
+    let mut l1 = vec![0.9, 0.5];
+    competition.inhibit(&mut l1, &[&a, &b], &L1);
+    let mut cosine = vec![0.9, 0.5];
+    competition.inhibit(&mut cosine, &[&a, &b], &Cosine);
+
+    assert!(l1[1] > 0.4, "L1 sees them as far apart");
+    assert_eq!(cosine[1], 0.0, "Cosine sees them as twins");
+}
+
+#[test]
+fn fully_inhibited_twins_still_produce_a_winner() {
+    let mut a = BiasField::new(3);
+    a.pref = vec![0.1, 0.1, 0.0];
+    let b = a.clone();
+
+    let mut competition = BiasCompetition::new(CompetitionMode::Proportional, 1).with_inhibition(1.0, 0.1);
+    let mut fitness = vec![0.3, 0.3, f32::NEG_INFINITY];
+    competition.inhibit(&mut fitness, &[&a, &b, &a], &L1);
+
+    assert_eq!(&fitness[..2], &[0.0, 0.0]);
+    assert_eq!(competition.select(&fitness), Some(0), "Matching basins lost to a new one");
+    assert_eq!(competition.select(&[f32::NEG_INFINITY]), None);
+}
+
+#[test]
+fn nan_strength_does_not_panic_thinking() {
+    let mut brain = InternalStateField::new();
+    brain.apply_disturbance(&Disturbance::new(0.3, 2.0, 0.1));
+    brain.biases[0].strength = f32::NAN;
+
+    brain.internal_thinking_tick();
+    assert!(brain.tension().is_finite());
+}
+
+#[test]
+fn stochastic_competition_is_reproducible() {
+    let run = || {
+        let mut brain = InternalStateField::new();
+        brain.competition = BiasCompetition::new(CompetitionMode::Softmax { temperature: 0.05 }, 9);
+
+        for i in 0..200 {
+            let d = Disturbance::new(0.1 + 0.004 * i as f32, 1.0, (i % 7) as f32 * 0.1);
+            brain.apply_disturbance(&d);
+            brain.internal_thinking_tick();
+        }
+        (brain.biases.len(), brain.state.clone())
+    };
+
+    assert_eq!(run(), run());
+}
@@ -0,0 +1,64 @@
+use project_newborn::{isf::InternalStateField, disturbance::Disturbance};
+use project_newborn::bias::{BiasField, BiasRecall};
+
+/// Two learned directions: "settle" when calm, "panic" when tense
+fn two_minded_brain(recall: BiasRecall) -> InternalStateField {
+    let mut brain = InternalStateField::new();
+    brain.bias_recall = recall;
+
+    let mut settle = BiasField::new(3);
+    settle.pref = vec![-0.2, 0.2, 0.0];
+    settle.context = vec![0.2, 0.8, 0.5];
+    settle.strength = 0.6;
+
+    let mut panic = BiasField::new(3);
+    panic.pref = vec![0.3, -0.2, -0.1];
+    panic.context = vec![1.3, 0.1, 0.5];
+    panic.strength = 0.8;
+
+    brain.biases = vec![settle, panic];
+    brain
+}
+
+fn drift_from(recall: BiasRecall, start: [f32; 3]) -> f32 {
+    let mut brain = two_minded_brain(recall);
+    brain.state.0 = start.to_vec();
+    brain.bias_thinking_tick();
+    brain.tension() - start[0]
+}
+
+#[test]
+fn strongest_recall_ignores_context() {
+    let calm = drift_from(BiasRecall::Strongest, [0.2, 0.8, 0.5]);
+    let tense = drift_from(BiasRecall::Strongest, [1.3, 0.1, 0.5]);
+
+    assert!(calm > 0.0 && tense > 0.0, "Strongest bias should win everywhere");
+}
+
+#[test]
+fn contextual_recall_depends_on_where_the_brain_is() {
+    let recall = BiasRecall::Contextual { width: 0.3 };
+
+    assert!(drift_from(recall, [0.2, 0.8, 0.5]) < 0.0, "Calm brain should settle");
+    assert!(drift_from(recall, [1.3, 0.1, 0.5]) > 0.0, "Tense brain should panic");
+}
+
+#[test]
+fn blended_recall_follows_nearest_basin() {
+    let recall = BiasRecall::Blend { width: 0.3 };
+
+    assert!(drift_from(recall, [0.2, 0.8, 0.5]) < 0.0);
+    assert!(drift_from(recall, [1.3, 0.1, 0.5]) > 0.0);
+}
+
+#[test]
+fn reinforcement_records_context() {
+    let mut brain = InternalStateField::new();
+    let start = brain.state.clone();
+
+    brain.apply_disturbance(&Disturbance::new(0.3, 2.0, 0.1));
+
+    let b = &brain.biases[0];
+    assert_eq!(b.count, 1);
+    assert_eq!(b.context, start.0);
+}
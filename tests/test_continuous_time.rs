@@ -0,0 +1,92 @@
+use project_newborn::{isf::InternalStateField, disturbance::Disturbance};
+use project_newborn::dynamics::{Integrator, TimeStep};
+
+fn unfold(d: &Disturbance, dt: f32, integrator: Integrator) -> (f32, f32, f32) {
+    let mut brain = InternalStateField::new();
+    // Start close to the ceiling so the saturating band shapes the trajectory
+    for i in 0..3 {
+        brain.state[i] = 0.85;
+    }
+    brain.apply_disturbance_over_time(d, TimeStep { dt, integrator });
+    (brain.tension(), brain.stability(), brain.energy())
+}
+
+fn gap(a: (f32, f32, f32), b: (f32, f32, f32)) -> f32 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs() + (a.2 - b.2).abs()
+}
+
+#[test]
+fn small_dt_converges() {
+    let d = Disturbance::new(0.7, 3.0, 0.6);
+
+    // Euler feels the saturating band at coarse steps
+    let coarse = unfold(&d, 0.5, Integrator::Euler);
+    let fine = unfold(&d, 0.05, Integrator::Euler);
+    let finest = unfold(&d, 0.005, Integrator::Euler);
+    assert!(gap(fine, finest) < gap(coarse, finest), "Euler not converging");
+
+    for integrator in [Integrator::Euler, Integrator::Rk4] {
+        let coarse = unfold(&d, 0.5, integrator);
+        let fine = unfold(&d, 0.05, integrator);
+        let finest = unfold(&d, 0.005, integrator);
+
+        assert!(gap(coarse, finest) < 1e-2, "{:?} coarse step diverges", integrator);
+        assert!(gap(fine, finest) < 1e-3, "{:?} not converging", integrator);
+    }
+
+    // Both integrators agree in the limit
+    let euler = unfold(&d, 0.001, Integrator::Euler);
+    let rk4 = unfold(&d, 0.001, Integrator::Rk4);
+    assert!(gap(euler, rk4) < 1e-3);
+}
+
+#[test]
+fn calm_unfolds_over_time() {
+    let calm = Disturbance::new(0.3, 3.0, 0.1);
+    let mut brain = InternalStateField::new();
+
+    let mut stability = Vec::new();
+    brain.apply_disturbance_interleaved(&calm, TimeStep { dt: 0.5, integrator: Integrator::Rk4 }, |b| {
+        stability.push(b.stability());
+    });
+
+    assert_eq!(stability.len(), 6);
+    assert!(stability.windows(2).all(|w| w[1] > w[0]), "Calm did not build gradually");
+    assert_eq!(brain.biases.len(), 1, "Experience was not learned once at the end");
+}
+
+/// Stepped and instant deltas agree within 5% of each delta
+fn assert_matches_instant(d: &Disturbance) {
+    let birth = InternalStateField::new().state;
+
+    let mut instant = InternalStateField::new();
+    instant.apply_disturbance(d);
+
+    let mut stepped = InternalStateField::new();
+    stepped.apply_disturbance_over_time(d, TimeStep::default());
+
+    for i in 0..3 {
+        let want = instant.state[i] - birth[i];
+        let got = stepped.state[i] - birth[i];
+        assert!(want.abs() > 1e-3, "dimension {i} did not move");
+        assert!((got - want).abs() <= 0.05 * want.abs(), "dimension {i}: stepped {got} vs instant {want}");
+    }
+}
+
+#[test]
+fn continuous_matches_instant_with_headroom() {
+    assert_matches_instant(&Disturbance::new(0.3, 2.0, 0.1));
+    assert_matches_instant(&Disturbance::new(0.9, 1.0, 0.9));
+}
+
+#[test]
+fn zero_dt_is_raised_to_the_minimum() {
+    let d = Disturbance::new(0.7, 1.0, 0.6);
+
+    let zero = unfold(&d, 0.0, Integrator::Euler);
+    let negative = unfold(&d, -1.0, Integrator::Euler);
+    let minimum = unfold(&d, TimeStep::MIN_DT, Integrator::Euler);
+
+    assert_eq!(zero, minimum);
+    assert_eq!(negative, minimum);
+}
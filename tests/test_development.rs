@@ -0,0 +1,109 @@
+use project_newborn::development::{AgeCurve, CriticalPeriod, Development, Learning};
+use project_newborn::disturbance::Disturbance;
+use project_newborn::environment::{Runner, Scripted};
+use project_newborn::isf::InternalStateField;
+use project_newborn::timeline::Timeline;
+
+fn rock() -> Disturbance {
+    Disturbance::new(0.3, 3.0, 0.1)
+}
+
+#[test]
+fn age_counts_experiences_and_thinking() {
+    let mut isf = InternalStateField::new();
+    assert_eq!(isf.age, 0);
+
+    isf.apply_disturbance(&rock());
+    isf.internal_thinking_tick();
+    isf.internal_thinking_tick();
+
+    assert_eq!(isf.age, 3);
+    assert_eq!(isf.snapshot(0).age, 3);
+}
+
+#[test]
+fn a_long_event_is_lived_tick_by_tick() {
+    let mut isf = InternalStateField::new();
+    let rocking = Disturbance::new(0.3, 120.0, 0.1);
+
+    let snaps = Timeline::new().at(0, rocking).run(&mut isf);
+
+    assert_eq!(isf.age, snaps.len() as u64);
+}
+
+#[test]
+fn disturbances_sharing_a_tick_age_once() {
+    let mut isf = InternalStateField::new();
+    let mut env = Scripted::new(vec![vec![rock(); 5], Vec::new()]);
+
+    Runner::passive().run(&mut isf, &mut env, 2);
+
+    assert_eq!(isf.age, 2);
+}
+
+#[test]
+fn continuous_disturbance_ages_by_its_duration() {
+    let mut isf = InternalStateField::new();
+    isf.apply_disturbance_over_time(&rock(), Default::default());
+
+    assert_eq!(isf.age, 3);
+}
+
+#[test]
+fn age_curves_fall_toward_their_floor() {
+    let exp = AgeCurve::Exponential { half_life: 100.0, floor: 0.1 };
+    assert_eq!(exp.at(0), 1.0);
+    assert!((exp.at(100) - 0.55).abs() < 1e-6);
+    assert!(exp.at(10_000) >= 0.1);
+
+    let lin = AgeCurve::Linear { end: 100, floor: 0.2 };
+    assert!((lin.at(50) - 0.6).abs() < 1e-6);
+    assert_eq!(lin.at(500), 0.2);
+
+    assert_eq!(AgeCurve::Constant.at(1_000_000), 1.0);
+}
+
+#[test]
+fn older_brain_reinforces_more_slowly() {
+    let aging = Development::new(AgeCurve::Exponential { half_life: 50.0, floor: 0.1 });
+
+    let mut young = InternalStateField::new();
+    young.development = aging.clone();
+
+    let mut old = InternalStateField::new();
+    old.development = aging;
+    old.age = 1_000;
+
+    young.apply_disturbance(&rock());
+    old.apply_disturbance(&rock());
+
+    assert!(young.biases[0].strength > old.biases[0].strength);
+}
+
+#[test]
+fn critical_period_amplifies_then_locks_in() {
+    let period = CriticalPeriod::new(Learning::Rule("regulation_learning".to_string()), 0, 20, 3.0, 0.0);
+    let development = Development::default().with_period(period);
+
+    assert_eq!(development.multiplier(&Learning::Rule("regulation_learning".to_string()), 10), 3.0);
+    assert_eq!(development.multiplier(&Learning::Rule("regulation_learning".to_string()), 21), 0.0);
+    assert_eq!(development.multiplier(&Learning::Reinforcement, 21), 1.0);
+
+    let mut inside = InternalStateField::new();
+    inside.development = development.clone();
+    let mut plain = InternalStateField::new();
+
+    inside.apply_disturbance(&rock());
+    plain.apply_disturbance(&rock());
+    assert!(inside.stability_gain_rate > plain.stability_gain_rate);
+
+    // After the window closes, calm no longer retunes regulation
+    let mut late = InternalStateField::new();
+    late.development = development;
+    late.age = 100;
+    let before = late.stability_gain_rate;
+    for _ in 0..10 {
+        late.apply_disturbance(&rock());
+    }
+    assert_eq!(late.stability_gain_rate, before);
+}
@@ -0,0 +1,63 @@
+use project_newborn::{isf::InternalStateField, disturbance::Disturbance};
+use project_newborn::bias::BiasField;
+use project_newborn::genome::Genome;
+use project_newborn::state::Dimension;
+
+fn hungry_genome() -> Genome {
+    Genome::default()
+        .with_dimension(Dimension::new("hunger", 0.2, (0.0, 1.0)))
+        .with_dimension(Dimension::new("warmth", 0.5, (0.0, 1.0)))
+}
+
+#[test]
+fn extra_dimensions_flow_through_memory() {
+    let mut brain = InternalStateField::with_config(hungry_genome());
+    assert_eq!(brain.state.len(), 5);
+    assert_eq!(brain.value("hunger"), Some(0.2));
+
+    for i in 0..20 {
+        let d = Disturbance::new(0.1 * (i % 10) as f32, 1.0, 0.05 * (i % 10) as f32);
+        brain.apply_disturbance(&d);
+    }
+
+    assert!(brain.biases.iter().all(|b| b.pref.len() == 5));
+    assert!(brain.memory.iter().all(|t| t.before.len() == 5 && t.after.len() == 5));
+}
+
+#[test]
+fn bias_replay_moves_extra_dimensions() {
+    let mut brain = InternalStateField::with_config(hungry_genome());
+
+    let mut craving = BiasField::new(5);
+    craving.pref[3] = 0.5;
+    craving.strength = 1.0;
+    brain.biases.push(craving);
+
+    for _ in 0..10 {
+        brain.internal_thinking_tick();
+    }
+
+    assert!(brain.value("hunger").unwrap() > 0.2);
+    assert_eq!(brain.value("warmth"), Some(0.5));
+}
+
+#[test]
+fn named_values_respect_limits() {
+    let mut brain = InternalStateField::with_config(hungry_genome());
+
+    assert!(brain.set_value("warmth", 3.0));
+    assert_eq!(brain.value("warmth"), Some(1.0));
+    assert!(!brain.set_value("thirst", 0.5));
+    assert_eq!(brain.value("thirst"), None);
+}
+
+#[test]
+fn core_preset_is_three_dimensional() {
+    let brain = InternalStateField::new();
+    let names: Vec<&str> = brain.genome.dimensions.iter().map(|d| d.name.as_str()).collect();
+
+    assert_eq!(names, ["tension", "stability", "energy"]);
+    assert_eq!(brain.tension(), 0.5);
+    assert_eq!(brain.stability(), 0.1);
+    assert_eq!(brain.energy(), 0.5);
+}
@@ -0,0 +1,74 @@
+use project_newborn::behavior::Behavior;
+use project_newborn::caregiver::Caregiver;
+use project_newborn::disturbance::Disturbance;
+use project_newborn::environment::{Environment, RandomEvents, Runner, Scripted};
+use project_newborn::isf::InternalStateField;
+use project_newborn::state::TENSION;
+
+#[test]
+fn scripted_birth_reproduces_main() {
+    let mut isf = InternalStateField::new();
+    let mut env = Scripted::birth();
+
+    let ticks = env.len() as u32;
+    let history = Runner::passive().run(&mut isf, &mut env, ticks);
+
+    // Same numbers main.rs prints
+    assert_eq!(&isf.state[..], &[0.69766134, 0.26296526, 0.59246385]);
+    assert_eq!(history.len(), 103);
+    assert_eq!(history[0].disturbances.len(), 1);
+    assert!(history[3].disturbances.is_empty());
+    assert!(history.iter().all(|r| r.action.is_none()));
+}
+
+#[test]
+fn runner_records_actions_and_snapshots() {
+    let mut isf = InternalStateField::new();
+    let mut env = Scripted::sequence(vec![Disturbance::new(0.9, 0.1, 0.9)], 5);
+
+    let history = Runner::new().run(&mut isf, &mut env, 6);
+
+    assert_eq!(history.len(), 6);
+    for (i, r) in history.iter().enumerate() {
+        assert_eq!(r.tick, i as u32);
+        assert_eq!(r.snapshot.tick, i as u32);
+        assert!(r.action.is_some());
+    }
+    assert_eq!(history[0].snapshot.active_disturbances, 1);
+    assert_eq!(history.last().unwrap().snapshot.state, isf.state);
+}
+
+#[test]
+fn random_events_are_seeded() {
+    let run = |seed| {
+        let mut isf = InternalStateField::new();
+        let mut env = RandomEvents::new(seed, 0.3);
+        Runner::new().run(&mut isf, &mut env, 200);
+        isf.state
+    };
+
+    assert_eq!(run(7), run(7));
+    assert_ne!(run(7), run(8));
+}
+
+#[test]
+fn random_events_respect_probability() {
+    let mut env = RandomEvents::new(1, 0.25);
+    let snap = InternalStateField::new().snapshot(0);
+    let events: usize = (0..4000).map(|_| env.step(&snap, None).len()).sum();
+    assert!((800..1200).contains(&events));
+}
+
+#[test]
+fn caregiver_answers_cries_after_latency() {
+    let mut isf = InternalStateField::new();
+    isf.state[TENSION] = 1.4;
+    let mut env = Caregiver::reactive(3);
+
+    let history = Runner::new().run(&mut isf, &mut env, 10);
+
+    assert_eq!(history[0].action.unwrap().behavior, Behavior::Cry);
+    let first_soothe = history.iter().position(|r| !r.disturbances.is_empty()).unwrap();
+    assert_eq!(first_soothe, 4);
+    assert!(history[first_soothe].snapshot.tension() < history[first_soothe - 1].snapshot.tension());
+}
@@ -0,0 +1,71 @@
+use project_newborn::{isf::InternalStateField, disturbance::Disturbance};
+use project_newborn::memory::EvictionPolicy;
+
+fn lifelong(brain: &mut InternalStateField) {
+    for i in 0..1000 {
+        let d = if i % 10 == 0 {
+            Disturbance::new(0.9, 0.1, 0.9)
+        } else {
+            Disturbance::new(0.2, 1.0, 0.1)
+        };
+        brain.apply_disturbance(&d);
+    }
+}
+
+#[test]
+fn episodic_buffer_stays_bounded() {
+    for policy in [
+        EvictionPolicy::Fifo,
+        EvictionPolicy::LowestSalience,
+        EvictionPolicy::Reservoir,
+    ] {
+        let mut brain = InternalStateField::with_memory(32, policy);
+        lifelong(&mut brain);
+
+        assert_eq!(brain.memory.len(), 32, "{:?} overflowed or underfilled", policy);
+        assert_eq!(brain.memory.seen(), 1000);
+    }
+}
+
+#[test]
+fn fifo_keeps_most_recent_episodes() {
+    let mut brain = InternalStateField::with_memory(4, EvictionPolicy::Fifo);
+
+    for i in 0..10 {
+        brain.apply_disturbance(&Disturbance::new(0.1 * i as f32, 1.0, 0.1));
+    }
+
+    let kept: Vec<f32> = brain.memory.iter().map(|t| t.disturbance.intensity).collect();
+    let expected = [0.6, 0.7, 0.8, 0.9];
+    for (k, e) in kept.iter().zip(expected) {
+        assert!((k - e).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn lowest_salience_keeps_shocks() {
+    let mut brain = InternalStateField::with_memory(8, EvictionPolicy::LowestSalience);
+    lifelong(&mut brain);
+
+    let shocks = brain
+        .memory
+        .iter()
+        .filter(|t| t.disturbance.suddenness > 0.5)
+        .count();
+
+    assert!(shocks >= 4, "Salient shocks were evicted ({} kept)", shocks);
+}
+
+#[test]
+fn trace_replay_uses_recorded_episodes() {
+    use project_newborn::isf::ReplayMode;
+
+    let mut brain = InternalStateField::new();
+    brain.replay_mode = ReplayMode::TraceBased;
+    brain.apply_disturbance(&Disturbance::new(0.9, 0.1, 0.9));
+
+    let before = brain.tension();
+    brain.trace_thinking_tick();
+
+    assert!(brain.tension() > before, "Trace replay did not re-live the shock");
+}
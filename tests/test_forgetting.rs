@@ -0,0 +1,96 @@
+use project_newborn::{isf::InternalStateField, disturbance::Disturbance};
+use project_newborn::bias::BiasField;
+use project_newborn::forgetting::{Forgetting, ForgettingCurve};
+use project_newborn::timeline::Timeline;
+
+fn learned_brain() -> InternalStateField {
+    let mut brain = InternalStateField::new();
+    brain.apply_disturbance(&Disturbance::new(0.3, 2.0, 0.1));
+    brain.apply_disturbance(&Disturbance::new(0.9, 0.1, 0.9));
+    brain
+}
+
+#[test]
+fn default_thinking_does_not_forget() {
+    let mut brain = learned_brain();
+    let before: Vec<f32> = brain.biases.iter().map(|b| b.strength).collect();
+
+    for _ in 0..100 {
+        brain.internal_thinking_tick();
+    }
+
+    let after: Vec<f32> = brain.biases.iter().map(|b| b.strength).collect();
+    assert_eq!(before, after);
+}
+
+#[test]
+fn idle_thinking_forgets_and_prunes() {
+    let mut brain = learned_brain();
+    brain.forgetting = Forgetting {
+        on_disturbance: None,
+        on_tick: Some(ForgettingCurve::Exponential { factor: 0.95 }),
+        prune_floor: 0.01,
+    };
+
+    let pruned: usize = (0..200).map(|_| brain.internal_thinking_tick().pruned).sum();
+
+    assert_eq!(pruned, 2);
+    assert!(brain.biases.is_empty());
+}
+
+#[test]
+fn pruning_is_reported_in_snapshots() {
+    let mut brain = learned_brain();
+    brain.forgetting.on_tick = Some(ForgettingCurve::Exponential { factor: 0.9 });
+    brain.forgetting.prune_floor = 0.02;
+
+    let snaps = Timeline::new().rest(50).run(&mut brain);
+
+    assert_eq!(snaps.iter().map(|s| s.pruned).sum::<usize>(), 2);
+    assert_eq!(snaps.last().unwrap().bias_count, 0);
+}
+
+#[test]
+fn replayed_basins_decay_slower() {
+    let curve = ForgettingCurve::UseDependent {
+        factor: 0.99,
+        protection: 1.0,
+    };
+
+    let mut used = BiasField::new(3);
+    used.strength = 1.0;
+    used.replays = 20;
+    let mut unused = used.clone();
+    unused.replays = 0;
+
+    for _ in 0..100 {
+        curve.apply(&mut used);
+        curve.apply(&mut unused);
+    }
+
+    assert!(used.strength > unused.strength * 2.0);
+}
+
+#[test]
+fn power_law_has_a_heavy_tail() {
+    let power = ForgettingCurve::PowerLaw { exponent: 0.5 };
+    let expo = ForgettingCurve::Exponential { factor: 0.995 };
+
+    let mut a = BiasField::new(3);
+    a.strength = 1.0;
+    let mut b = a.clone();
+
+    curve_steps(power, &mut a, 1);
+    curve_steps(expo, &mut b, 1);
+    assert!(a.strength < b.strength, "Power law should forget fast at first");
+
+    curve_steps(power, &mut a, 1999);
+    curve_steps(expo, &mut b, 1999);
+    assert!(a.strength > b.strength, "Power law should keep a long tail");
+}
+
+fn curve_steps(curve: ForgettingCurve, b: &mut BiasField, n: usize) {
+    for _ in 0..n {
+        curve.apply(b);
+    }
+}
@@ -0,0 +1,62 @@
+use project_newborn::{isf::InternalStateField, disturbance::Disturbance};
+use project_newborn::genome::Genome;
+use project_newborn::state::TENSION;
+
+#[test]
+fn default_genome_reproduces_the_original_newborn() {
+    // Numbers produced by the hard-coded constants before Genome existed
+    let mut brain = InternalStateField::with_config(Genome::default());
+
+    let shock = Disturbance::new(0.9, 0.1, 0.9);
+    let calm = Disturbance::new(0.3, 2.0, 0.1);
+
+    for d in [&shock, &calm, &shock, &calm] {
+        brain.apply_disturbance(d);
+    }
+
+    assert_eq!((brain.tension(), brain.stability(), brain.energy()), (0.83599997, 0.28281, 0.6559999));
+    assert_eq!(
+        (brain.shock_sensitivity, brain.stability_gain_rate, brain.energy_gain_rate),
+        (0.2, 0.05202, 0.1)
+    );
+    assert_eq!(brain.biases.len(), 1);
+
+    for _ in 0..50 {
+        brain.internal_thinking_tick();
+    }
+
+    assert_eq!((brain.tension(), brain.stability(), brain.energy()), (0.8525909, 0.29183713, 0.59370387));
+    assert_eq!(brain.biases.len(), 1);
+}
+
+#[test]
+fn sensitive_temperament_reacts_harder() {
+    let jumpy = Genome {
+        shock_sensitivity: 0.4,
+        ..Genome::default()
+    };
+
+    let mut calm_baby = InternalStateField::new();
+    let mut jumpy_baby = InternalStateField::with_config(jumpy);
+
+    let shock = Disturbance::new(0.9, 0.1, 0.9);
+    calm_baby.apply_disturbance(&shock);
+    jumpy_baby.apply_disturbance(&shock);
+
+    assert!(jumpy_baby.tension() > calm_baby.tension());
+}
+
+#[test]
+fn genome_limits_are_respected() {
+    let mut fragile = Genome::default();
+    fragile.dimensions[TENSION].range = (0.0, 0.8);
+
+    let mut brain = InternalStateField::with_config(fragile);
+    let shock = Disturbance::new(1.0, 0.1, 1.0);
+
+    for _ in 0..50 {
+        brain.apply_disturbance(&shock);
+    }
+
+    assert!(brain.tension() <= 0.8);
+}
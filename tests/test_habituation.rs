@@ -0,0 +1,102 @@
+use project_newborn::disturbance::Disturbance;
+use project_newborn::habituation::{fingerprint, Habituation};
+use project_newborn::isf::InternalStateField;
+use project_newborn::state::TENSION;
+
+const DOOR: u64 = 1;
+const ALARM: u64 = 2;
+
+fn tension_jump(isf: &mut InternalStateField, d: &Disturbance) -> f32 {
+    let before = isf.tension();
+    isf.apply_disturbance(d);
+    isf.tension() - before
+}
+
+#[test]
+fn repetition_dampens_only_that_stimulus() {
+    let mut isf = InternalStateField::new();
+    let door = Disturbance::new(0.5, 0.1, 0.9).with_stimulus(DOOR);
+
+    let first = tension_jump(&mut isf, &door);
+    for _ in 0..5 {
+        tension_jump(&mut isf, &door);
+        isf.state[TENSION] = 0.5;
+    }
+    let later = tension_jump(&mut isf, &door);
+    assert!(later < first * 0.6);
+
+    // A new alarm is felt at full force
+    let mut fresh = InternalStateField::new();
+    isf.state = fresh.state.clone();
+    let alarm = Disturbance::new(0.5, 0.1, 0.9).with_stimulus(ALARM);
+    let felt = tension_jump(&mut isf, &alarm);
+    let baseline = tension_jump(&mut fresh, &alarm);
+    assert!((felt - baseline).abs() < 1e-6);
+}
+
+#[test]
+fn novel_stimulus_dishabituates() {
+    let mut h = Habituation::default();
+    let door = Disturbance::new(0.5, 0.1, 0.9).with_stimulus(DOOR);
+
+    for _ in 0..5 {
+        h.register(&door);
+    }
+    let habituated = h.gain(DOOR);
+
+    h.register(&Disturbance::new(0.5, 0.1, 0.9).with_stimulus(ALARM));
+    assert!(h.gain(DOOR) > habituated);
+
+    // Only novelty dishabituates; the alarm again does nothing for the door
+    let after_novelty = h.gain(DOOR);
+    h.register(&Disturbance::new(0.5, 0.1, 0.9).with_stimulus(ALARM));
+    assert_eq!(h.gain(DOOR), after_novelty);
+}
+
+#[test]
+fn habituation_recovers_over_idle_time() {
+    let mut isf = InternalStateField::new();
+    let door = Disturbance::new(0.5, 0.1, 0.9).with_stimulus(DOOR);
+    for _ in 0..5 {
+        isf.apply_disturbance(&door);
+    }
+    let habituated = isf.habituation.gain(DOOR);
+
+    for _ in 0..200 {
+        isf.internal_thinking_tick();
+    }
+    let rested = isf.habituation.gain(DOOR);
+
+    assert!(rested > habituated);
+    assert!(rested <= 1.0);
+}
+
+#[test]
+fn gain_never_drops_below_floor() {
+    let mut h = Habituation::default();
+    let door = Disturbance::new(0.5, 0.1, 0.9).with_stimulus(DOOR);
+    for _ in 0..1000 {
+        h.register(&door);
+    }
+    assert!(h.gain(DOOR) >= h.floor);
+}
+
+#[test]
+fn anonymous_disturbances_never_habituate() {
+    let mut h = Habituation::default();
+    let d = Disturbance::new(0.5, 0.1, 0.9);
+    for _ in 0..10 {
+        h.register(&d);
+    }
+    assert!(h.is_empty());
+    assert_eq!(h.felt(&d).intensity, d.intensity);
+}
+
+#[test]
+fn fingerprints_identify_similar_features() {
+    assert_eq!(fingerprint(&[0.31, 0.7]), fingerprint(&[0.29, 0.71]));
+    assert_ne!(fingerprint(&[0.3, 0.7]), fingerprint(&[0.7, 0.3]));
+
+    let d = Disturbance::new(0.5, 0.1, 0.9).with_fingerprint(&[0.3, 0.7]);
+    assert_eq!(d.stimulus, Some(fingerprint(&[0.3, 0.7])));
+}
@@ -0,0 +1,50 @@
+use project_newborn::isf::{InternalStateField, ReplayMode};
+use project_newborn::homeostasis::SetPointHomeostasis;
+use project_newborn::state::{STABILITY, TENSION};
+
+#[test]
+fn stability_bonus_applied_once_per_tick() {
+    for mode in [ReplayMode::StructuralBias, ReplayMode::TraceBased] {
+        let mut brain = InternalStateField::new();
+        brain.replay_mode = mode;
+        brain.state[TENSION] = 0.2;
+
+        let before = brain.stability();
+        brain.internal_thinking_tick();
+
+        assert!(
+            (brain.stability() - before - 0.01).abs() < 1e-6,
+            "{:?} applied homeostasis more than once",
+            mode
+        );
+    }
+}
+
+#[test]
+fn set_point_releases_tension_when_stable() {
+    let mut brain = InternalStateField::new();
+    brain.homeostasis = Box::new(SetPointHomeostasis::default());
+    brain.state[TENSION] = 1.2;
+    brain.state[STABILITY] = 0.9;
+
+    for _ in 0..100 {
+        brain.internal_thinking_tick();
+    }
+
+    assert!(brain.tension() < 0.6, "Tension not released: {}", brain.tension());
+    assert!((brain.energy() - 0.5).abs() < 0.05);
+}
+
+#[test]
+fn set_point_keeps_tension_when_unstable() {
+    let mut brain = InternalStateField::new();
+    brain.homeostasis = Box::new(SetPointHomeostasis::default());
+    brain.state[TENSION] = 1.2;
+    brain.state[STABILITY] = 0.1;
+
+    for _ in 0..100 {
+        brain.internal_thinking_tick();
+    }
+
+    assert!((brain.tension() - 1.2).abs() < 1e-6);
+}
@@ -0,0 +1,124 @@
+use project_newborn::disturbance::{Disturbance, Modality};
+use project_newborn::interoception::{Drive, DriveGrowth, Interoception, Relief};
+use project_newborn::isf::InternalStateField;
+
+fn hungry_brain() -> InternalStateField {
+    let mut isf = InternalStateField::new();
+    isf.interoception = Interoception::default().with_drive(Drive::hunger());
+    isf
+}
+
+fn feed() -> Disturbance {
+    Disturbance::new(0.6, 2.0, 0.1)
+        .with_valence(1.0)
+        .with_modality(Modality::Taste)
+}
+
+#[test]
+fn no_drives_by_default() {
+    let mut isf = InternalStateField::new();
+    for _ in 0..500 {
+        assert_eq!(isf.internal_thinking_tick().urges, 0);
+    }
+    assert!(isf.snapshot(0).drives.is_empty());
+}
+
+#[test]
+fn hunger_builds_until_it_disturbs_the_brain() {
+    let mut isf = hungry_brain();
+
+    let mut first_urge = None;
+    for tick in 0..400 {
+        if isf.internal_thinking_tick().urges > 0 {
+            first_urge = Some(tick);
+            break;
+        }
+    }
+
+    // 0.7 / 0.004 ≈ 175 ticks
+    let tick = first_urge.expect("hunger never fired");
+    assert!((170..=180).contains(&tick));
+
+    let snap = isf.snapshot(0);
+    assert_eq!(snap.drives[0].0, "hunger");
+    assert!(snap.drives[0].1 >= 0.7);
+}
+
+#[test]
+fn urges_are_internal_aversive_disturbances() {
+    let mut drive = Drive::hunger();
+    drive.level = 0.9;
+    let urge = drive.tick(0).unwrap();
+
+    assert_eq!(urge.modality, Some(Modality::Interoceptive));
+    assert!(urge.valence < 0.0);
+
+    // Refractory: no new urge right away
+    assert!(drive.tick(1).is_none());
+}
+
+#[test]
+fn feeding_satisfies_hunger() {
+    let mut isf = hungry_brain();
+    isf.interoception.drive_mut("hunger").unwrap().level = 0.9;
+
+    isf.apply_disturbance(&feed());
+    assert!(isf.interoception.drive("hunger").unwrap().level < 0.7);
+
+    // A slap in the mouth is not a meal
+    isf.interoception.drive_mut("hunger").unwrap().level = 0.9;
+    isf.apply_disturbance(&feed().with_valence(-1.0));
+    assert_eq!(isf.interoception.drive("hunger").unwrap().level, 0.9);
+}
+
+#[test]
+fn sleep_relieves_sleepiness_only() {
+    let mut isf = InternalStateField::new();
+    isf.interoception = Interoception::newborn();
+    for d in isf.interoception.drives.iter_mut() {
+        d.level = 0.9;
+    }
+
+    isf.sleep(20);
+
+    assert!(isf.interoception.drive("sleepiness").unwrap().level < 0.1);
+    assert_eq!(isf.interoception.drive("hunger").unwrap().level, 0.9);
+}
+
+#[test]
+fn periodic_drive_rises_about_once_per_period() {
+    let mut drive = Drive::new(
+        "temperature",
+        DriveGrowth::Periodic { period: 100.0, phase: 0.0, ease: 0.1 },
+        2.0,
+        Disturbance::new(0.4, 1.0, 0.3),
+        Relief::Modality(Modality::Temperature),
+    );
+
+    let levels: Vec<f32> = (0..400)
+        .map(|age| {
+            drive.tick(age);
+            drive.level
+        })
+        .collect();
+
+    // Every period it rises near the top, then falls back near the bottom
+    for period in levels.chunks(100) {
+        let (rise, fall) = period.split_at(50);
+        assert!(rise.iter().copied().fold(0.0, f32::max) > 0.85, "Did not rise");
+        assert!(fall.iter().copied().fold(1.0, f32::min) < 0.15, "Did not fall again");
+    }
+}
+
+#[test]
+fn periodic_urges_stop_between_peaks() {
+    let mut drive = Drive::temperature_discomfort();
+
+    let fired: Vec<bool> = (0..900).map(|age| drive.tick(age).is_some()).collect();
+
+    // Three days, three bouts of discomfort, quiet in between
+    for day in fired.chunks(300) {
+        assert!(day.iter().any(|f| *f), "No discomfort this cycle");
+        assert!(day[150..].iter().all(|f| !*f), "Still firing at the cool end of the cycle");
+    }
+}
@@ -0,0 +1,49 @@
+use project_newborn::{isf::InternalStateField, disturbance::Disturbance};
+use project_newborn::plasticity::{Plasticity, PlasticityInput, PlasticityRule};
+
+#[test]
+fn ablated_rule_stops_learning() {
+    let calm = Disturbance::new(0.3, 3.0, 0.1);
+
+    let mut intact = InternalStateField::new();
+    let mut ablated = InternalStateField::new();
+    ablated.ablate_rule("regulation_learning");
+
+    for _ in 0..10 {
+        intact.apply_disturbance(&calm);
+        ablated.apply_disturbance(&calm);
+    }
+
+    assert!(intact.stability_gain_rate > ablated.genome.stability_gain_rate);
+    assert_eq!(ablated.stability_gain_rate, ablated.genome.stability_gain_rate);
+}
+
+/// Shock under poor regulation makes the brain MORE reactive
+#[derive(Debug)]
+struct Sensitization;
+
+impl PlasticityRule for Sensitization {
+    fn name(&self) -> &str {
+        "sensitization"
+    }
+
+    fn adapt(&mut self, input: &PlasticityInput, p: &mut Plasticity) {
+        if input.shock > 0.3 && input.stability < 0.3 {
+            p.shock_sensitivity *= 1.05;
+        }
+    }
+}
+
+#[test]
+fn custom_rule_is_applied() {
+    let shock = Disturbance::new(0.9, 0.1, 0.9);
+
+    let mut brain = InternalStateField::new();
+    brain.plasticity_rules.push(Box::new(Sensitization));
+
+    for _ in 0..5 {
+        brain.apply_disturbance(&shock);
+    }
+
+    assert!(brain.shock_sensitivity > brain.genome.shock_sensitivity);
+}
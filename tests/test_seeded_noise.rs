@@ -0,0 +1,58 @@
+use project_newborn::{isf::InternalStateField, disturbance::Disturbance};
+use project_newborn::noise::{MindWandering, NoiseDistribution, NoiseSource, SeededNoise};
+
+fn wandering_run(seed: u64, distribution: NoiseDistribution) -> Vec<(f32, f32, f32)> {
+    let mut brain = InternalStateField::new();
+    brain.noise = Some(MindWandering::seeded(seed, distribution, 0.02));
+
+    brain.apply_disturbance(&Disturbance::new(0.9, 0.1, 0.9));
+    brain.apply_disturbance(&Disturbance::new(0.3, 2.0, 0.1));
+
+    (0..200)
+        .map(|_| {
+            brain.internal_thinking_tick();
+            (brain.tension(), brain.stability(), brain.energy())
+        })
+        .collect()
+}
+
+#[test]
+fn same_seed_is_bit_identical() {
+    for dist in [
+        NoiseDistribution::Uniform,
+        NoiseDistribution::Gaussian,
+        NoiseDistribution::Pink,
+    ] {
+        let a = wandering_run(42, dist);
+        let b = wandering_run(42, dist);
+
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert_eq!(x.0.to_bits(), y.0.to_bits());
+            assert_eq!(x.1.to_bits(), y.1.to_bits());
+            assert_eq!(x.2.to_bits(), y.2.to_bits());
+        }
+    }
+}
+
+#[test]
+fn different_seeds_wander_differently() {
+    let a = wandering_run(1, NoiseDistribution::Gaussian);
+    let b = wandering_run(2, NoiseDistribution::Gaussian);
+
+    assert!(a.iter().zip(b.iter()).any(|(x, y)| x != y));
+}
+
+#[test]
+fn noise_is_roughly_zero_mean() {
+    for dist in [
+        NoiseDistribution::Uniform,
+        NoiseDistribution::Gaussian,
+        NoiseDistribution::Pink,
+    ] {
+        let mut noise = SeededNoise::new(7, dist);
+        let n = 20_000;
+        let mean: f32 = (0..n).map(|_| noise.sample()).sum::<f32>() / n as f32;
+
+        assert!(mean.abs() < 0.1, "{:?} mean {}", dist, mean);
+    }
+}
@@ -0,0 +1,124 @@
+use project_newborn::disturbance::Disturbance;
+use project_newborn::isf::InternalStateField;
+use project_newborn::plasticity::Sensitization;
+use project_newborn::state::STABILITY;
+
+fn slam() -> Disturbance {
+    Disturbance::new(0.9, 0.1, 0.9)
+}
+
+fn rock() -> Disturbance {
+    Disturbance::new(0.3, 3.0, 0.1)
+}
+
+/// Sensitization is opt-in
+fn sensitizable() -> InternalStateField {
+    let mut isf = InternalStateField::new();
+    isf.plasticity_rules.push(Box::new(Sensitization::from_genome(&isf.genome)));
+    isf
+}
+
+fn neglected(shocks: usize) -> InternalStateField {
+    let mut isf = sensitizable();
+    for _ in 0..shocks {
+        isf.apply_disturbance(&slam());
+    }
+    isf
+}
+
+#[test]
+fn default_newborn_never_sensitizes() {
+    let mut isf = InternalStateField::new();
+    for _ in 0..40 {
+        isf.apply_disturbance(&slam());
+    }
+
+    assert_eq!(isf.shock_sensitivity, isf.genome.shock_sensitivity);
+    assert!(!isf.is_hypervigilant());
+}
+
+#[test]
+fn range_is_only_exceeded_with_the_rule_installed() {
+    let mut isf = InternalStateField::new();
+    isf.shock_sensitivity = 0.7;
+    isf.apply_disturbance(&slam());
+    assert_eq!(isf.shock_sensitivity, isf.genome.shock_sensitivity_range.1);
+}
+
+#[test]
+fn hypervigilance_is_not_undone_by_desensitization() {
+    let mut isf = neglected(40);
+    let sensitized = isf.shock_sensitivity;
+
+    // Well regulated, but still shocked: no calm, no recovery
+    isf.state[STABILITY] = 1.0;
+    for _ in 0..10 {
+        isf.apply_disturbance(&slam());
+        isf.state[STABILITY] = 1.0;
+    }
+
+    assert_eq!(isf.shock_sensitivity, sensitized);
+}
+
+#[test]
+fn single_shock_does_not_sensitize() {
+    let isf = neglected(1);
+    assert_eq!(isf.shock_sensitivity, isf.genome.shock_sensitivity);
+}
+
+#[test]
+fn repeated_shock_under_low_stability_sensitizes_into_hypervigilance() {
+    let isf = neglected(40);
+
+    assert!(isf.is_hypervigilant());
+    assert!(isf.shock_sensitivity <= isf.genome.hypervigilance_limit);
+}
+
+#[test]
+fn well_regulated_brain_desensitizes_instead() {
+    let mut isf = sensitizable();
+    for _ in 0..30 {
+        isf.apply_disturbance(&rock());
+    }
+    assert!(isf.stability() > 0.6);
+
+    for _ in 0..10 {
+        isf.apply_disturbance(&slam());
+        isf.apply_disturbance(&rock());
+    }
+
+    assert!(isf.shock_sensitivity < isf.genome.shock_sensitivity);
+}
+
+#[test]
+fn thinking_alone_does_not_recover() {
+    let mut isf = neglected(20);
+    let sensitized = isf.shock_sensitivity;
+
+    for _ in 0..200 {
+        isf.internal_thinking_tick();
+    }
+
+    assert_eq!(isf.shock_sensitivity, sensitized);
+}
+
+#[test]
+fn only_sustained_calm_recovers() {
+    let mut interrupted = neglected(20);
+    let sensitized = interrupted.shock_sensitivity;
+
+    // Calm broken up by shocks never adds up
+    for _ in 0..10 {
+        interrupted.apply_disturbance(&rock());
+        interrupted.apply_disturbance(&rock());
+        interrupted.apply_disturbance(&slam());
+    }
+
+    let mut soothed = neglected(20);
+    for _ in 0..30 {
+        soothed.apply_disturbance(&rock());
+    }
+    assert!(soothed.shock_sensitivity < sensitized);
+    assert!(soothed.shock_sensitivity < interrupted.shock_sensitivity);
+    assert!(soothed.shock_sensitivity >= soothed.genome.shock_sensitivity);
+}
@@ -0,0 +1,68 @@
+use project_newborn::disturbance::Disturbance;
+use project_newborn::isf::InternalStateField;
+use project_newborn::state::{ENERGY, TENSION};
+
+fn busy_day() -> InternalStateField {
+    let mut isf = InternalStateField::new();
+    for i in 0..12 {
+        let d = if i % 3 == 0 {
+            Disturbance::new(0.9, 0.1, 0.9)
+        } else {
+            Disturbance::new(0.3, 2.0, 0.1)
+        };
+        isf.apply_disturbance(&d);
+    }
+    isf
+}
+
+#[test]
+fn sleep_empties_the_episodic_buffer() {
+    let mut isf = busy_day();
+    assert!(!isf.memory.is_empty());
+
+    isf.sleep(10);
+
+    assert!(isf.memory.is_empty());
+    assert_eq!(isf.memory.seen(), 0, "Reservoir count survived sleep");
+}
+
+#[test]
+fn sleep_replays_experiences_into_biases() {
+    let awake = busy_day();
+    let mut asleep = busy_day();
+
+    asleep.sleep(0);
+
+    let depth = |isf: &InternalStateField| isf.biases.iter().map(|b| b.count).sum::<u32>();
+    assert!(depth(&asleep) > depth(&awake));
+}
+
+#[test]
+fn sleep_merges_and_prunes_basins() {
+    let mut isf = busy_day();
+    // Two copies of one basin, one dead basin
+    let b = isf.biases[0].clone();
+    isf.biases.push(b);
+    isf.forgetting.prune_floor = 0.01;
+    let mut dead = isf.biases[0].clone();
+    dead.pref = vec![5.0, 5.0, 5.0];
+    dead.strength = 0.001;
+    isf.biases.push(dead);
+
+    let report = isf.sleep(1);
+
+    assert!(report.merged > 0);
+    assert!(report.pruned > 0);
+}
+
+#[test]
+fn sleep_restores_energy_and_lowers_tension() {
+    let mut isf = busy_day();
+    isf.state[TENSION] = 1.2;
+    isf.state[ENERGY] = 0.2;
+
+    isf.sleep(30);
+
+    assert!(isf.tension() < 0.4);
+    assert!(isf.energy() > 0.6);
+}
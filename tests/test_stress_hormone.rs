@@ -0,0 +1,79 @@
+use project_newborn::disturbance::Disturbance;
+use project_newborn::hormone::StressHormone;
+use project_newborn::isf::InternalStateField;
+use project_newborn::state::TENSION;
+
+fn slam() -> Disturbance {
+    Disturbance::new(0.9, 0.1, 0.9)
+}
+
+fn with_hormone() -> InternalStateField {
+    let mut isf = InternalStateField::new();
+    isf.hormone = Some(StressHormone::default());
+    isf
+}
+
+#[test]
+fn hormone_peaks_after_the_shock_then_clears() {
+    let mut isf = with_hormone();
+    isf.apply_disturbance(&slam());
+    // Only the shock's own tick of release has happened
+    let at_shock = isf.hormone_level();
+
+    let mut levels = Vec::new();
+    for _ in 0..100 {
+        isf.internal_thinking_tick();
+        levels.push(isf.hormone_level());
+    }
+
+    let peak = levels
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .map(|(i, _)| i)
+        .unwrap();
+    assert!(peak > 0);
+    assert!(levels[peak] > at_shock * 2.0);
+    assert!(levels[99] < levels[peak] * 0.1);
+}
+
+#[test]
+fn lingering_hormone_raises_tension_after_the_shock() {
+    let mut instant = InternalStateField::new();
+    let mut delayed = with_hormone();
+
+    instant.apply_disturbance(&slam());
+    delayed.apply_disturbance(&slam());
+    assert_eq!(instant.tension(), delayed.tension());
+
+    for _ in 0..10 {
+        instant.internal_thinking_tick();
+        delayed.internal_thinking_tick();
+    }
+    assert!(delayed.tension() > instant.tension());
+    assert_eq!(delayed.snapshot(0).hormone, delayed.hormone_level());
+}
+
+#[test]
+fn hormone_suppresses_stability_gain() {
+    let mut h = StressHormone::default();
+    assert_eq!(h.stability_factor(), 1.0);
+
+    h.drive(0.8);
+    for _ in 0..5 {
+        h.tick();
+    }
+    assert!(h.stability_factor() < 1.0);
+
+    let mut calm = InternalStateField::new();
+    let mut stressed = with_hormone();
+    calm.state[TENSION] = 0.1;
+    stressed.state[TENSION] = 0.1;
+    stressed.hormone.as_mut().unwrap().level = 0.5;
+
+    for _ in 0..5 {
+        calm.internal_thinking_tick();
+        stressed.internal_thinking_tick();
+    }
+    assert!(stressed.stability() < calm.stability());
+}
@@ -0,0 +1,85 @@
+use project_newborn::{isf::InternalStateField, disturbance::Disturbance};
+use project_newborn::dynamics::{Integrator, Overlap, TimeStep};
+use project_newborn::timeline::Timeline;
+
+#[test]
+fn shock_rest_shock_emits_one_snapshot_per_tick() {
+    let shock = Disturbance::new(0.9, 0.2, 0.9);
+
+    let timeline = Timeline::new()
+        .then(shock.clone())
+        .rest(20)
+        .then(shock);
+
+    let mut brain = InternalStateField::new();
+    let snaps = timeline.run(&mut brain);
+
+    assert_eq!(snaps.len(), 22);
+    assert_eq!(snaps[0].active_disturbances, 1);
+    assert!(snaps[1..21].iter().all(|s| s.active_disturbances == 0));
+    assert_eq!(snaps[21].active_disturbances, 1);
+    assert!(snaps.iter().enumerate().all(|(i, s)| s.tick == i as u32));
+
+    // Second shock pushes tension up again after the rest
+    assert!(snaps[21].tension() > snaps[20].tension());
+}
+
+#[test]
+fn overlapping_noise_while_rocked() {
+    let rocking = Disturbance::new(0.3, 6.0, 0.1);
+    let noise = Disturbance::new(0.9, 1.0, 0.9);
+
+    let alone = Timeline::new().at(0, rocking.clone());
+    let both = Timeline::new().at(0, rocking).at(2, noise);
+
+    let mut calm_baby = InternalStateField::new();
+    let mut startled_baby = InternalStateField::new();
+
+    let a = alone.run(&mut calm_baby);
+    let b = both.run(&mut startled_baby);
+
+    assert_eq!(a.len(), b.len());
+    assert_eq!(b[2].active_disturbances, 2);
+    assert!(b[2].tension() > a[2].tension(), "Noise did not add to rocking");
+}
+
+#[test]
+fn max_overlap_is_gentler_than_sum() {
+    let a = Disturbance::new(0.8, 2.0, 0.8);
+    let b = Disturbance::new(0.8, 2.0, 0.8);
+
+    let sum = Timeline::new().at(0, a.clone()).at(0, b.clone());
+    let max = Timeline::new().at(0, a).at(0, b).overlap(Overlap::Max);
+
+    let mut x = InternalStateField::new();
+    let mut y = InternalStateField::new();
+    sum.run(&mut x);
+    max.run(&mut y);
+
+    assert!(x.tension() > y.tension());
+}
+
+#[test]
+fn each_disturbance_is_learned_once() {
+    let timeline = Timeline::new()
+        .then(Disturbance::new(0.3, 3.0, 0.1))
+        .rest(5);
+
+    let mut brain = InternalStateField::new();
+    timeline.run(&mut brain);
+
+    assert_eq!(brain.memory.len(), 1);
+    assert_eq!(brain.biases.len(), 1);
+}
+
+#[test]
+fn zero_dt_still_finishes() {
+    let timeline = Timeline::new()
+        .at(0, Disturbance::new(0.5, 2.0, 0.3))
+        .time_step(TimeStep { dt: 0.0, integrator: Integrator::Euler });
+
+    let mut brain = InternalStateField::new();
+    let snaps = timeline.run(&mut brain);
+
+    assert_eq!(snaps.len(), 2);
+}
@@ -3,7 +3,7 @@ use project_newborn::isf::ReplayMode;
 
 #[test]
 fn compare_trace_and_structural_replay() {
-    let disturbances = vec![
+    let disturbances = [
         Disturbance::new(0.9, 0.1, 0.9),
         Disturbance::new(0.3, 2.0, 0.1),
         Disturbance::new(0.4, 1.5, 0.2),
@@ -17,6 +17,10 @@ fn compare_trace_and_structural_replay() {
         trace_brain.apply_disturbance(d);
     }
 
+    assert_eq!(trace_brain.memory.len(), disturbances.len(), "Episodes were not recorded");
+
+    let trace_start = (trace_brain.tension(), trace_brain.stability());
+
     for _ in 0..50 {
         trace_brain.internal_thinking_tick();
     }
@@ -29,18 +33,41 @@ fn compare_trace_and_structural_replay() {
         bias_brain.apply_disturbance(d);
     }
 
+    let bias_start = (bias_brain.tension(), bias_brain.stability());
+
     for _ in 0..50 {
         bias_brain.internal_thinking_tick();
     }
 
     // -------- Assertions (directional, not exact) --------
+    let trace_dt = trace_brain.tension() - trace_start.0;
+    let bias_dt = bias_brain.tension() - bias_start.0;
     assert!(
-        (trace_brain.tension - bias_brain.tension).abs() < 0.05,
-        "Tension drift mismatch too large"
+        (trace_dt - bias_dt).abs() < 0.05,
+        "Tension drift mismatch too large: trace {trace_dt} vs bias {bias_dt}"
     );
 
+    let trace_ds = trace_brain.stability() - trace_start.1;
+    let bias_ds = bias_brain.stability() - bias_start.1;
     assert!(
-        (trace_brain.stability - bias_brain.stability).abs() < 0.05,
-        "Stability drift mismatch too large"
+        (trace_ds - bias_ds).abs() < 0.05,
+        "Stability drift mismatch too large: trace {trace_ds} vs bias {bias_ds}"
     );
 }
+
+#[test]
+fn trace_replay_fades() {
+    let mut brain = InternalStateField::new();
+    brain.replay_mode = ReplayMode::TraceBased;
+    brain.apply_disturbance(&Disturbance::new(0.3, 2.0, 0.1));
+
+    let start = brain.stability();
+    for _ in 0..500 {
+        brain.internal_thinking_tick();
+    }
+
+    // A single episode cannot push the brain further than its own delta
+    let delta = brain.memory.iter().next().map(|t| t.delta()).unwrap();
+    let bound = delta.iter().map(|d| d.abs()).sum::<f32>();
+    assert!((brain.stability() - start).abs() <= bound, "Replay ran away");
+}
@@ -0,0 +1,80 @@
+use project_newborn::disturbance::{Disturbance, Modality};
+use project_newborn::dynamics::TimeStep;
+use project_newborn::isf::InternalStateField;
+
+#[test]
+fn neutral_valence_is_the_default() {
+    let d = Disturbance::new(0.5, 1.0, 0.3);
+    assert_eq!(d.valence, 0.0);
+    assert_eq!(d.modality, None);
+    assert_eq!(d.comfort(), 0.0);
+    assert_eq!(d.harm(), 0.0);
+}
+
+#[test]
+fn feed_and_slap_of_equal_intensity_diverge() {
+    let feed = Disturbance::new(0.6, 1.0, 0.3)
+        .with_valence(1.0)
+        .with_modality(Modality::Taste);
+    let slap = Disturbance::new(0.6, 1.0, 0.3)
+        .with_valence(-1.0)
+        .with_modality(Modality::Touch);
+
+    let mut fed = InternalStateField::new();
+    let mut hit = InternalStateField::new();
+    let mut plain = InternalStateField::new();
+
+    fed.apply_disturbance(&feed);
+    hit.apply_disturbance(&slap);
+    plain.apply_disturbance(&Disturbance::new(0.6, 1.0, 0.3));
+
+    assert!(fed.tension() < plain.tension());
+    assert!(hit.tension() > plain.tension());
+    assert!(fed.energy() > plain.energy());
+    assert_eq!(hit.energy(), plain.energy());
+}
+
+#[test]
+fn valence_acts_in_continuous_time() {
+    let feed = Disturbance::new(0.6, 4.0, 0.3).with_valence(1.0);
+    let slap = Disturbance::new(0.6, 4.0, 0.3).with_valence(-1.0);
+
+    let mut fed = InternalStateField::new();
+    let mut hit = InternalStateField::new();
+    fed.apply_disturbance_over_time(&feed, TimeStep::default());
+    hit.apply_disturbance_over_time(&slap, TimeStep::default());
+
+    assert!(fed.tension() < hit.tension());
+    assert!(fed.energy() > hit.energy());
+}
+
+#[test]
+fn biases_record_approach_and_avoid() {
+    let mut fed = InternalStateField::new();
+    let mut hit = InternalStateField::new();
+
+    for _ in 0..5 {
+        fed.apply_disturbance(&Disturbance::new(0.6, 1.0, 0.3).with_valence(1.0));
+        hit.apply_disturbance(&Disturbance::new(0.6, 1.0, 0.3).with_valence(-1.0));
+    }
+
+    assert!(fed.biases.iter().all(|b| b.tendency() > 0.0));
+    assert!(hit.biases.iter().all(|b| b.tendency() < 0.0));
+}
+
+#[test]
+fn mixed_experiences_blend_into_the_basin() {
+    let mut isf = InternalStateField::new();
+
+    isf.apply_disturbance(&Disturbance::new(0.3, 1.0, 0.3).with_valence(1.0));
+    isf.apply_disturbance(&Disturbance::new(0.3, 1.0, 0.3).with_valence(-0.5));
+
+    assert_eq!(isf.biases.len(), 1);
+    assert!((isf.biases[0].valence - 0.25).abs() < 1e-6);
+}
+
+#[test]
+fn valence_is_clamped() {
+    assert_eq!(Disturbance::new(1.0, 1.0, 0.0).with_valence(3.0).valence, 1.0);
+    assert_eq!(Disturbance::new(1.0, 1.0, 0.0).with_valence(-3.0).valence, -1.0);
+}